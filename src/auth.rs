@@ -0,0 +1,191 @@
+//! Twilio API credentials.
+
+use core::fmt;
+
+///Environment variable with account SID.
+pub const ACCOUNT_SID_ENV: &str = "TWILIO_ACCOUNT_SID";
+///Environment variable with account's auth token.
+pub const AUTH_TOKEN_ENV: &str = "TWILIO_AUTH_TOKEN";
+///Environment variable with API key SID.
+pub const API_KEY_ENV: &str = "TWILIO_API_KEY";
+///Environment variable with API key's secret.
+pub const API_SECRET_ENV: &str = "TWILIO_API_SECRET";
+
+const BASE64_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(input: &[u8], out: &mut String) {
+    out.reserve(input.len().div_ceil(3) * 4);
+
+    for chunk in input.chunks(3) {
+        let b0 = chunk[0] as usize;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as usize;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as usize;
+
+        out.push(BASE64_TABLE[b0 >> 2] as char);
+        out.push(BASE64_TABLE[((b0 & 0x03) << 4) | (b1 >> 4)] as char);
+        match chunk.len() {
+            1 => out.push_str("=="),
+            2 => {
+                out.push(BASE64_TABLE[((b1 & 0x0f) << 2) | (b2 >> 6)] as char);
+                out.push('=');
+            },
+            _ => {
+                out.push(BASE64_TABLE[((b1 & 0x0f) << 2) | (b2 >> 6)] as char);
+                out.push(BASE64_TABLE[b2 & 0x3f] as char);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+///Error loading credentials.
+pub enum CredentialsError {
+    ///Environment variable is not set or is not valid unicode.
+    MissingEnv(&'static str),
+}
+
+impl fmt::Display for CredentialsError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CredentialsError::MissingEnv(name) => fmt.write_fmt(format_args!("Environment variable '{}' is not set", name)),
+        }
+    }
+}
+
+impl std::error::Error for CredentialsError {
+}
+
+#[derive(Clone, PartialEq, Eq)]
+///Credentials to access Twilio REST API.
+///
+///`Debug` implementation never prints secrets.
+pub enum Credentials {
+    ///Account SID with its auth token.
+    AuthToken {
+        ///Account SID.
+        account_sid: String,
+        ///Auth token.
+        auth_token: String,
+    },
+    ///API key, acting on behalf of account.
+    ApiKey {
+        ///Account SID, which owns resources.
+        account_sid: String,
+        ///API key SID.
+        key_sid: String,
+        ///API key's secret.
+        secret: String,
+    },
+}
+
+impl Credentials {
+    #[inline]
+    ///Creates credentials from account SID and auth token.
+    pub fn auth_token(account_sid: &str, auth_token: &str) -> Self {
+        Credentials::AuthToken {
+            account_sid: account_sid.to_owned(),
+            auth_token: auth_token.to_owned(),
+        }
+    }
+
+    #[inline]
+    ///Creates credentials from API key SID and its secret.
+    pub fn api_key(account_sid: &str, key_sid: &str, secret: &str) -> Self {
+        Credentials::ApiKey {
+            account_sid: account_sid.to_owned(),
+            key_sid: key_sid.to_owned(),
+            secret: secret.to_owned(),
+        }
+    }
+
+    ///Loads credentials from environment.
+    ///
+    ///If `TWILIO_API_KEY` is set, then it is used together with `TWILIO_API_SECRET`.
+    ///Otherwise `TWILIO_AUTH_TOKEN` is used.
+    ///
+    ///`TWILIO_ACCOUNT_SID` is always required.
+    pub fn from_env() -> Result<Self, CredentialsError> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    ///Loads credentials from variables, provided by `get`.
+    ///
+    ///Follows the same rules as `from_env`, but variables are looked up via `get` instead of environment.
+    pub fn from_vars<F: Fn(&'static str) -> Option<String>>(get: F) -> Result<Self, CredentialsError> {
+        let var = |name: &'static str| get(name).ok_or(CredentialsError::MissingEnv(name));
+
+        let account_sid = var(ACCOUNT_SID_ENV)?;
+        match var(API_KEY_ENV) {
+            Ok(key_sid) => Ok(Credentials::ApiKey {
+                account_sid,
+                key_sid,
+                secret: var(API_SECRET_ENV)?,
+            }),
+            Err(_) => Ok(Credentials::AuthToken {
+                account_sid,
+                auth_token: var(AUTH_TOKEN_ENV)?,
+            }),
+        }
+    }
+
+    #[inline]
+    ///Returns account SID, to be used in URL of resources.
+    pub fn account_sid(&self) -> &str {
+        match self {
+            Credentials::AuthToken { account_sid, .. } => account_sid,
+            Credentials::ApiKey { account_sid, .. } => account_sid,
+        }
+    }
+
+    #[inline]
+    ///Returns username for HTTP Basic authentication.
+    pub fn username(&self) -> &str {
+        match self {
+            Credentials::AuthToken { account_sid, .. } => account_sid,
+            Credentials::ApiKey { key_sid, .. } => key_sid,
+        }
+    }
+
+    #[inline]
+    ///Returns password for HTTP Basic authentication.
+    pub fn password(&self) -> &str {
+        match self {
+            Credentials::AuthToken { auth_token, .. } => auth_token,
+            Credentials::ApiKey { secret, .. } => secret,
+        }
+    }
+
+    ///Returns value of `Authorization` header, using HTTP Basic authentication.
+    pub fn authorization(&self) -> String {
+        let username = self.username();
+        let password = self.password();
+
+        let mut plain = String::with_capacity(username.len() + password.len() + 1);
+        plain.push_str(username);
+        plain.push(':');
+        plain.push_str(password);
+
+        let mut result = String::from("Basic ");
+        base64_encode(plain.as_bytes(), &mut result);
+        result
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        const REDACTED: &str = "<redacted>";
+
+        match self {
+            Credentials::AuthToken { account_sid, .. } => fmt.debug_struct("AuthToken")
+                                                              .field("account_sid", account_sid)
+                                                              .field("auth_token", &REDACTED)
+                                                              .finish(),
+            Credentials::ApiKey { account_sid, key_sid, .. } => fmt.debug_struct("ApiKey")
+                                                                    .field("account_sid", account_sid)
+                                                                    .field("key_sid", key_sid)
+                                                                    .field("secret", &REDACTED)
+                                                                    .finish(),
+        }
+    }
+}
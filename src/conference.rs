@@ -0,0 +1,537 @@
+//! Conference and participant resources.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{Host, ResourcePath, Resource, ResourceUrl, ResultDateTime, PageMeta, TwilioRequest};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Status of conference.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum ConferenceStatus {
+    ///Conference is created, but has not started yet.
+    Init,
+    ///Conference is ongoing.
+    InProgress,
+    ///Conference is over.
+    Completed,
+    ///Status, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(ConferenceStatus {
+    Init => "init",
+    InProgress => "in-progress",
+    Completed => "completed",
+});
+
+#[derive(Debug, Deserialize)]
+///Conference resource.
+pub struct Conference {
+    ///ID of conference
+    pub sid: String,
+    ///ID of account, which owns conference.
+    pub account_sid: Option<String>,
+    ///API version used to create conference.
+    pub api_version: Option<String>,
+    ///Name of conference, as given in `<Conference>`.
+    pub friendly_name: Option<String>,
+    ///Status of conference.
+    pub status: ConferenceStatus,
+    ///Region, where conference is mixed.
+    pub region: Option<String>,
+    ///Reason of why conference ended.
+    pub reason_conference_ended: Option<String>,
+    ///ID of call, which ended conference.
+    pub call_sid_ending_conference: Option<String>,
+    ///Timestamp (including zone) of when conference is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp (including zone) of when conference is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+    ///URIs of related resources, relative to `https://api.twilio.com`
+    pub subresource_uris: Option<HashMap<String, String>>,
+}
+
+impl Conference {
+    #[inline]
+    ///Creates URL to list conferences of the account.
+    pub const fn list_url(host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Conferences).json()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or update conference `sid`.
+    pub const fn url(host: &Host<'_>, account_sid: &str, sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Conferences).sid(sid).json()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of conferences.
+pub struct ConferenceList {
+    ///Conferences
+    pub conferences: Vec<Conference>,
+    #[serde(flatten)]
+    ///Paging information.
+    pub page: PageMeta,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Status to set on the live conference.
+pub enum ConferenceUpdateStatus {
+    ///Ends conference, disconnecting all participants.
+    Completed,
+}
+
+impl ConferenceUpdateStatus {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ConferenceUpdateStatus::Completed => "completed",
+        }
+    }
+}
+
+#[derive(Debug)]
+///Describes modification of conference, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Conference::url`
+pub struct ConferenceUpdate<'a> {
+    ///ID of conference
+    pub sid: &'a str,
+    ///Whether to end conference, disconnecting all participants
+    pub end: bool,
+    ///URL of audio or TwiML to announce to all participants
+    pub announce_url: Option<&'a str>,
+}
+
+impl<'a> ConferenceUpdate<'a> {
+    #[inline]
+    ///Creates update of conference `sid` without any modification.
+    pub const fn new(sid: &'a str) -> Self {
+        Self {
+            sid,
+            end: false,
+            announce_url: None,
+        }
+    }
+
+    #[inline]
+    ///Creates update, ending conference `sid`.
+    pub const fn end(sid: &'a str) -> Self {
+        Self {
+            sid,
+            end: true,
+            announce_url: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post update onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        Conference::url(host, account_sid, self.sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        if self.end {
+            res.conference_status(ConferenceUpdateStatus::Completed);
+        }
+        if let Some(url) = self.announce_url {
+            res.announce_url(url);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for ConferenceUpdate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Status of conference participant.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum ParticipantStatus {
+    ///Participant's call is queued.
+    Queued,
+    ///Participant's call is connecting.
+    Connecting,
+    ///Participant's call is ringing.
+    Ringing,
+    ///Participant is in conference.
+    Connected,
+    ///Participant left conference.
+    Complete,
+    ///Participant failed to join.
+    Failed,
+    ///Status, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(ParticipantStatus {
+    Queued => "queued",
+    Connecting => "connecting",
+    Ringing => "ringing",
+    Connected => "connected",
+    Complete => "complete",
+    Failed => "failed",
+});
+
+#[derive(Debug, Deserialize)]
+///Conference participant resource.
+pub struct Participant {
+    ///ID of participant's call
+    pub call_sid: String,
+    ///ID of conference
+    pub conference_sid: String,
+    ///ID of account, which owns conference.
+    pub account_sid: Option<String>,
+    ///Label of participant.
+    pub label: Option<String>,
+    ///Status of participant.
+    pub status: Option<ParticipantStatus>,
+    #[serde(default)]
+    ///Whether participant is muted.
+    pub muted: bool,
+    #[serde(default)]
+    ///Whether participant is on hold.
+    pub hold: bool,
+    #[serde(default)]
+    ///Whether participant is coaching other participant.
+    pub coaching: bool,
+    ///ID of call, which participant coaches.
+    pub call_sid_to_coach: Option<String>,
+    #[serde(default)]
+    ///Whether conference starts when participant joins.
+    pub start_conference_on_enter: bool,
+    #[serde(default)]
+    ///Whether conference ends when participant leaves.
+    pub end_conference_on_exit: bool,
+    ///Timestamp (including zone) of when participant is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp (including zone) of when participant is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+}
+
+impl Participant {
+    #[inline]
+    ///Creates URL to list or add participants of conference `conference_sid`.
+    pub const fn list_url(host: &Host<'_>, account_sid: &str, conference_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Conferences).sid(conference_sid).resource(Resource::Participants).json()
+    }
+
+    #[inline]
+    ///Creates URL to fetch, update or remove participant `call_sid` of conference `conference_sid`.
+    ///
+    ///Participant is removed from conference by `DELETE` request onto this URL.
+    pub const fn url(host: &Host<'_>, account_sid: &str, conference_sid: &str, call_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Conferences).sid(conference_sid).resource(Resource::Participants).sid(call_sid).json()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of conference participants.
+pub struct ParticipantList {
+    ///Participants
+    pub participants: Vec<Participant>,
+    #[serde(flatten)]
+    ///Paging information.
+    pub page: PageMeta,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///When to play beep as participant joins or leaves conference.
+pub enum Beep {
+    ///Beep on both join and leave.
+    Always,
+    ///No beep.
+    Never,
+    ///Beep only on join.
+    OnEnter,
+    ///Beep only on leave.
+    OnExit,
+}
+
+impl Beep {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Beep::Always => "true",
+            Beep::Never => "false",
+            Beep::OnEnter => "onEnter",
+            Beep::OnExit => "onExit",
+        }
+    }
+}
+
+#[derive(Debug)]
+///Describes outbound participant to add to conference, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Participant::list_url`
+pub struct ParticipantCreate<'a> {
+    ///ID or friendly name of conference
+    pub conference_sid: &'a str,
+    ///Phone number or client identifier of source
+    pub from: &'a str,
+    ///Phone number, SIP address or client identifier of destination
+    pub to: &'a str,
+    ///Label of participant
+    pub label: Option<&'a str>,
+    ///Whether to connect ringing audio before participant answers
+    pub early_media: Option<bool>,
+    ///When to play beep
+    pub beep: Option<Beep>,
+    ///Whether participant is muted on join
+    pub muted: Option<bool>,
+    ///Whether conference starts when participant joins
+    pub start_conference_on_enter: Option<bool>,
+    ///Whether conference ends when participant leaves
+    pub end_conference_on_exit: Option<bool>,
+    ///ID of call to coach, making participant heard only by this call
+    pub call_sid_to_coach: Option<&'a str>,
+    ///URL where to post participant's call status information
+    pub status_callback: Option<&'a str>,
+}
+
+impl<'a> ParticipantCreate<'a> {
+    #[inline]
+    ///Creates participant calling `to` from `from`, with default parameters.
+    pub const fn new(conference_sid: &'a str, from: &'a str, to: &'a str) -> Self {
+        Self {
+            conference_sid,
+            from,
+            to,
+            label: None,
+            early_media: None,
+            beep: None,
+            muted: None,
+            start_conference_on_enter: None,
+            end_conference_on_exit: None,
+            call_sid_to_coach: None,
+            status_callback: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post participant onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        Participant::list_url(host, account_sid, self.conference_sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.from(self.from).to(self.to);
+        if let Some(label) = self.label {
+            res.label(label);
+        }
+        if let Some(early_media) = self.early_media {
+            res.early_media(early_media);
+        }
+        if let Some(beep) = self.beep {
+            res.beep(beep);
+        }
+        if let Some(muted) = self.muted {
+            res.muted(muted);
+        }
+        if let Some(value) = self.start_conference_on_enter {
+            res.start_conference_on_enter(value);
+        }
+        if let Some(value) = self.end_conference_on_exit {
+            res.end_conference_on_exit(value);
+        }
+        if let Some(call_sid) = self.call_sid_to_coach {
+            res.coaching(true).call_sid_to_coach(call_sid);
+        }
+        if let Some(url) = self.status_callback {
+            res.status_url(url);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for ParticipantCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug)]
+///Describes modification of conference participant, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Participant::url`
+pub struct ParticipantUpdate<'a> {
+    ///ID of conference
+    pub conference_sid: &'a str,
+    ///ID of participant's call
+    pub call_sid: &'a str,
+    ///Whether participant is muted
+    pub muted: Option<bool>,
+    ///Whether participant is on hold
+    pub hold: Option<bool>,
+    ///URL of audio or TwiML to play while participant is on hold
+    pub hold_url: Option<&'a str>,
+    ///URL of audio or TwiML to announce to participant
+    pub announce_url: Option<&'a str>,
+    ///Whether participant is coaching, `false` makes participant heard by everyone
+    pub coaching: Option<bool>,
+    ///ID of call to coach
+    pub call_sid_to_coach: Option<&'a str>,
+}
+
+impl<'a> ParticipantUpdate<'a> {
+    #[inline]
+    ///Creates update of participant `call_sid` without any modification.
+    pub const fn new(conference_sid: &'a str, call_sid: &'a str) -> Self {
+        Self {
+            conference_sid,
+            call_sid,
+            muted: None,
+            hold: None,
+            hold_url: None,
+            announce_url: None,
+            coaching: None,
+            call_sid_to_coach: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post update onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        Participant::url(host, account_sid, self.conference_sid, self.call_sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        if let Some(muted) = self.muted {
+            res.muted(muted);
+        }
+        if let Some(hold) = self.hold {
+            res.hold(hold);
+        }
+        if let Some(url) = self.hold_url {
+            res.hold_url(url);
+        }
+        if let Some(url) = self.announce_url {
+            res.announce_url(url);
+        }
+        if let Some(coaching) = self.coaching {
+            res.coaching(coaching);
+        }
+        if let Some(call_sid) = self.call_sid_to_coach {
+            res.call_sid_to_coach(call_sid);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for ParticipantUpdate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Event of conference status callback.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum ConferenceEvent {
+    ///Conference is started.
+    ConferenceStart,
+    ///Conference is ended.
+    ConferenceEnd,
+    ///Participant joined.
+    ParticipantJoin,
+    ///Participant left.
+    ParticipantLeave,
+    ///Participant is muted.
+    ParticipantMute,
+    ///Participant is unmuted.
+    ParticipantUnmute,
+    ///Participant is put on hold.
+    ParticipantHold,
+    ///Participant is taken off hold.
+    ParticipantUnhold,
+    ///Participant is modified, for example coaching is changed.
+    ParticipantModify,
+    ///Participant started speaking.
+    ParticipantSpeechStart,
+    ///Participant stopped speaking.
+    ParticipantSpeechStop,
+    ///Announcement finished.
+    AnnouncementEnd,
+    ///Announcement failed.
+    AnnouncementFail,
+    ///Event, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(ConferenceEvent {
+    ConferenceStart => "conference-start",
+    ConferenceEnd => "conference-end",
+    ParticipantJoin => "participant-join",
+    ParticipantLeave => "participant-leave",
+    ParticipantMute => "participant-mute",
+    ParticipantUnmute => "participant-unmute",
+    ParticipantHold => "participant-hold",
+    ParticipantUnhold => "participant-unhold",
+    ParticipantModify => "participant-modify",
+    ParticipantSpeechStart => "participant-speech-start",
+    ParticipantSpeechStop => "participant-speech-stop",
+    AnnouncementEnd => "announcement-end",
+    AnnouncementFail => "announcement-fail",
+});
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+///Payload of conference status callback.
+pub struct ConferenceStatusCallback {
+    ///ID of conference.
+    pub conference_sid: String,
+    ///Name of conference.
+    pub friendly_name: Option<String>,
+    ///ID of account.
+    pub account_sid: Option<String>,
+    ///Event, which caused callback.
+    pub status_callback_event: ConferenceEvent,
+    ///Order of callback, starting from 1.
+    pub sequence_number: Option<u32>,
+    ///Timestamp (including zone) of event.
+    pub timestamp: Option<ResultDateTime>,
+    ///ID of participant's call, for participant events.
+    pub call_sid: Option<String>,
+    ///Label of participant, for participant events.
+    pub participant_label: Option<String>,
+    ///Whether participant is muted.
+    pub muted: Option<bool>,
+    ///Whether participant is on hold.
+    pub hold: Option<bool>,
+    ///Whether participant is coaching.
+    pub coaching: Option<bool>,
+    ///Whether conference ends when participant leaves.
+    pub end_conference_on_exit: Option<bool>,
+    ///Whether conference starts when participant joins.
+    pub start_conference_on_enter: Option<bool>,
+    ///Reason of why conference ended, for `conference-end`.
+    pub reason_conference_ended: Option<String>,
+    ///ID of call, which ended conference, for `conference-end`.
+    pub call_sid_ending_conference: Option<String>,
+    ///Description of event.
+    pub reason: Option<String>,
+}
@@ -0,0 +1,402 @@
+//! Content v1 API `content.twilio.com/v1`
+//!
+//! Templates are created by posting `Content` as JSON body, using its `Serialize` implementation.
+//! Messages use template via `ContentMessage`, which sends `ContentSid` and `ContentVariables` instead of `Body`.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::ser::{self, Serialize, Serializer, SerializeMap, SerializeStruct};
+
+use super::{Host, ProductPath, Product, ResourceUrl, ResultDateTime, ListMeta, Sender, TwilioRequest};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Reply button of `QuickReplyContent`.
+pub struct QuickReply<'a> {
+    ///Text of button
+    pub title: &'a str,
+    ///Payload, sent back when button is pressed
+    pub id: &'a str,
+}
+
+impl<'a> Serialize for QuickReply<'a> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ser = serializer.serialize_struct("QuickReply", 2)?;
+        ser.serialize_field("title", self.title)?;
+        ser.serialize_field("id", self.id)?;
+        ser.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Button of `CallToActionContent` or `CardContent`.
+pub enum ContentAction<'a> {
+    ///Opens URL.
+    Url {
+        ///Text of button
+        title: &'a str,
+        ///URL to open, may contain placeholders
+        url: &'a str,
+    },
+    ///Calls phone number.
+    PhoneNumber {
+        ///Text of button
+        title: &'a str,
+        ///Phone number in E.164 format
+        phone: &'a str,
+    },
+    ///Sends payload back, only supported by `CardContent`.
+    QuickReply(QuickReply<'a>),
+}
+
+impl<'a> Serialize for ContentAction<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ser = serializer.serialize_struct("ContentAction", 3)?;
+        match self {
+            ContentAction::Url { title, url } => {
+                ser.serialize_field("type", "URL")?;
+                ser.serialize_field("title", title)?;
+                ser.serialize_field("url", url)?;
+            },
+            ContentAction::PhoneNumber { title, phone } => {
+                ser.serialize_field("type", "PHONE_NUMBER")?;
+                ser.serialize_field("title", title)?;
+                ser.serialize_field("phone", phone)?;
+            },
+            ContentAction::QuickReply(reply) => {
+                ser.serialize_field("type", "QUICK_REPLY")?;
+                ser.serialize_field("title", reply.title)?;
+                ser.serialize_field("id", reply.id)?;
+            },
+        }
+        ser.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Item of `ListPickerContent`.
+pub struct ListItem<'a> {
+    ///Payload, sent back when item is selected
+    pub id: &'a str,
+    ///Text of item
+    pub item: &'a str,
+    ///Description, shown below item
+    pub description: Option<&'a str>,
+}
+
+impl<'a> Serialize for ListItem<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ser = serializer.serialize_struct("ListItem", 3)?;
+        ser.serialize_field("id", self.id)?;
+        ser.serialize_field("item", self.item)?;
+        match self.description {
+            Some(description) => ser.serialize_field("description", description)?,
+            None => ser.skip_field("description")?,
+        }
+        ser.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///`twilio/text` content.
+pub struct TextContent<'a> {
+    ///Text of message
+    pub body: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///`twilio/media` content.
+pub struct MediaContent<'a> {
+    ///Text of message
+    pub body: Option<&'a str>,
+    ///URLs of media
+    pub media: &'a [&'a str],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///`twilio/quick-reply` content.
+pub struct QuickReplyContent<'a> {
+    ///Text of message
+    pub body: &'a str,
+    ///Reply buttons
+    pub actions: &'a [QuickReply<'a>],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///`twilio/call-to-action` content.
+pub struct CallToActionContent<'a> {
+    ///Text of message
+    pub body: &'a str,
+    ///Buttons, either `ContentAction::Url` or `ContentAction::PhoneNumber`
+    pub actions: &'a [ContentAction<'a>],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///`twilio/list-picker` content.
+pub struct ListPickerContent<'a> {
+    ///Text of message
+    pub body: &'a str,
+    ///Text of button, which opens list
+    pub button: &'a str,
+    ///Items of list
+    pub items: &'a [ListItem<'a>],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///`twilio/card` content.
+pub struct CardContent<'a> {
+    ///Title of card
+    pub title: &'a str,
+    ///Subtitle of card
+    pub subtitle: Option<&'a str>,
+    ///URLs of media
+    pub media: &'a [&'a str],
+    ///Buttons
+    pub actions: &'a [ContentAction<'a>],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Kind of content, with its data.
+///
+///Template can have multiple kinds, each used by channels which support it.
+pub enum ContentKind<'a> {
+    ///`twilio/text`
+    Text(TextContent<'a>),
+    ///`twilio/media`
+    Media(MediaContent<'a>),
+    ///`twilio/quick-reply`
+    QuickReply(QuickReplyContent<'a>),
+    ///`twilio/call-to-action`
+    CallToAction(CallToActionContent<'a>),
+    ///`twilio/list-picker`
+    ListPicker(ListPickerContent<'a>),
+    ///`twilio/card`
+    Card(CardContent<'a>),
+}
+
+impl<'a> ContentKind<'a> {
+    #[inline]
+    ///Returns name of kind, as used by Twilio.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ContentKind::Text(_) => "twilio/text",
+            ContentKind::Media(_) => "twilio/media",
+            ContentKind::QuickReply(_) => "twilio/quick-reply",
+            ContentKind::CallToAction(_) => "twilio/call-to-action",
+            ContentKind::ListPicker(_) => "twilio/list-picker",
+            ContentKind::Card(_) => "twilio/card",
+        }
+    }
+}
+
+impl<'a> Serialize for ContentKind<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ser = serializer.serialize_struct("ContentKind", 4)?;
+        match self {
+            ContentKind::Text(text) => {
+                ser.serialize_field("body", text.body)?;
+            },
+            ContentKind::Media(media) => {
+                match media.body {
+                    Some(body) => ser.serialize_field("body", body)?,
+                    None => ser.skip_field("body")?,
+                }
+                ser.serialize_field("media", media.media)?;
+            },
+            ContentKind::QuickReply(reply) => {
+                ser.serialize_field("body", reply.body)?;
+                ser.serialize_field("actions", reply.actions)?;
+            },
+            ContentKind::CallToAction(cta) => {
+                ser.serialize_field("body", cta.body)?;
+                ser.serialize_field("actions", cta.actions)?;
+            },
+            ContentKind::ListPicker(list) => {
+                ser.serialize_field("body", list.body)?;
+                ser.serialize_field("button", list.button)?;
+                ser.serialize_field("items", list.items)?;
+            },
+            ContentKind::Card(card) => {
+                ser.serialize_field("title", card.title)?;
+                match card.subtitle {
+                    Some(subtitle) => ser.serialize_field("subtitle", subtitle)?,
+                    None => ser.skip_field("subtitle")?,
+                }
+                ser.serialize_field("media", card.media)?;
+                ser.serialize_field("actions", card.actions)?;
+            },
+        }
+        ser.end()
+    }
+}
+
+struct Pairs<'a>(&'a [(&'a str, &'a str)]);
+
+impl<'a> Serialize for Pairs<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ser = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in self.0.iter() {
+            ser.serialize_entry(key, value)?;
+        }
+        ser.end()
+    }
+}
+
+struct Kinds<'a>(&'a [ContentKind<'a>]);
+
+impl<'a> Serialize for Kinds<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ser = serializer.serialize_map(Some(self.0.len()))?;
+        for (idx, kind) in self.0.iter().enumerate() {
+            if self.0[..idx].iter().any(|prev| prev.as_str() == kind.as_str()) {
+                return Err(ser::Error::custom(format_args!("Duplicate content kind {}", kind.as_str())));
+            }
+            ser.serialize_entry(kind.as_str(), kind)?;
+        }
+        ser.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+///Describes new content template, suitable for JSON serialization
+///
+///Request is to be posted onto `ContentTemplate::list_url` with `Content-Type` equal to `application/json`.
+pub struct Content<'a> {
+    ///Name of template
+    pub friendly_name: &'a str,
+    ///Language of template (e.g. `en`)
+    pub language: &'a str,
+    ///Default values of placeholders, as pairs of placeholder (e.g. `1` for `{{1}}`) and value
+    pub variables: &'a [(&'a str, &'a str)],
+    ///Kinds of content, each kind must be unique, otherwise serialization fails
+    pub types: &'a [ContentKind<'a>],
+}
+
+impl<'a> Content<'a> {
+    #[inline]
+    ///Creates template with `types` and no variables.
+    pub const fn new(friendly_name: &'a str, language: &'a str, types: &'a [ContentKind<'a>]) -> Self {
+        Self {
+            friendly_name,
+            language,
+            variables: &[],
+            types,
+        }
+    }
+}
+
+impl<'a> Serialize for Content<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ser = serializer.serialize_struct("Content", 4)?;
+        ser.serialize_field("friendly_name", self.friendly_name)?;
+        ser.serialize_field("language", self.language)?;
+        ser.serialize_field("variables", &Pairs(self.variables))?;
+        ser.serialize_field("types", &Kinds(self.types))?;
+        ser.end()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Content template.
+///
+///Data of content kinds is not parsed, only their names are kept in `types`.
+pub struct ContentTemplate {
+    ///ID of template
+    pub sid: String,
+    ///ID of account, which owns template.
+    pub account_sid: Option<String>,
+    ///Name of template.
+    pub friendly_name: Option<String>,
+    ///Language of template.
+    pub language: Option<String>,
+    #[serde(default)]
+    ///Default values of placeholders.
+    pub variables: HashMap<String, String>,
+    #[serde(default)]
+    ///Kinds of content (e.g. `twilio/text`).
+    pub types: HashMap<String, serde::de::IgnoredAny>,
+    ///Timestamp of when template is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when template is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+    #[serde(default)]
+    ///URLs of sub-resources.
+    pub links: HashMap<String, String>,
+}
+
+impl ContentTemplate {
+    #[inline]
+    ///Creates URL to list or create templates.
+    pub const fn list_url(host: &Host<'_>) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Content).segment("Content").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or delete template `sid`.
+    pub const fn url(host: &Host<'_>, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Content).segment("Content").sid(sid).into_url()
+    }
+
+    #[inline]
+    ///Returns whether template has content of `kind` (e.g. `twilio/text`).
+    pub fn has_kind(&self, kind: &str) -> bool {
+        self.types.contains_key(kind)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of content templates.
+pub struct ContentTemplateList {
+    ///Templates
+    pub contents: Vec<ContentTemplate>,
+    ///Paging information.
+    pub meta: ListMeta,
+}
+
+#[derive(Debug)]
+///Describes message, using content template instead of body, suitable for urlencoded serialization
+///
+///Request is to be posted onto the same URL as `Sms`.
+pub struct ContentMessage<'a> {
+    ///Source of message
+    pub from: Sender<'a>,
+    ///Destination of message (e.g. `whatsapp:+14155552345`)
+    pub to: &'a str,
+    ///ID of content template
+    pub content_sid: &'a str,
+    ///Values of placeholders, as pairs of placeholder (e.g. `1` for `{{1}}`) and value
+    pub content_variables: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> ContentMessage<'a> {
+    #[inline]
+    ///Creates message with template `content_sid` and its default variables.
+    pub const fn new(from: Sender<'a>, to: &'a str, content_sid: &'a str) -> Self {
+        Self {
+            from,
+            to,
+            content_sid,
+            content_variables: &[],
+        }
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.sender(self.from).to(self.to).content_sid(self.content_sid);
+        if !self.content_variables.is_empty() {
+            res.content_variables(self.content_variables);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for ContentMessage<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
@@ -0,0 +1,301 @@
+//! Date and time representation.
+
+use core::fmt;
+use core::str::FromStr;
+
+use serde::ser::{Serialize, Serializer};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+
+const SECS_PER_DAY: i64 = 86_400;
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+
+//Days since 1970-01-01 to civil date (year, month, day).
+//Based on http://howardhinnant.github.io/date_algorithms.html
+const fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month as u32, day as u32)
+}
+
+//Civil date to days since 1970-01-01.
+const fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///UTC timestamp with precision of seconds.
+///
+///`Display` renders it in ISO 8601 format, as expected by Twilio API (e.g. `2021-11-30T20:36:27Z`).
+///
+///Parsing accepts both ISO 8601 and RFC 2822 (e.g. `Wed, 18 Aug 2010 20:01:40 +0000`), which is used in API responses.
+///Offset of time zone is applied, converting value to UTC.
+///
+///Serialized as RFC 2822, same as Twilio API does.
+pub struct TwilioDateTime {
+    unix: i64,
+}
+
+impl TwilioDateTime {
+    #[inline]
+    ///Creates timestamp from number of seconds since Unix epoch.
+    pub const fn from_unix(secs: i64) -> Self {
+        Self {
+            unix: secs,
+        }
+    }
+
+    ///Creates timestamp from date and time components in UTC.
+    ///
+    ///Returns `None` if any component is out of range.
+    pub const fn from_utc(year: i64, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Option<Self> {
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) || hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+
+        let days = days_from_civil(year, month, day);
+        Some(Self::from_unix(days * SECS_PER_DAY + (hour * 3_600 + minute * 60 + second) as i64))
+    }
+
+    #[inline]
+    ///Returns number of seconds since Unix epoch.
+    pub const fn unix(&self) -> i64 {
+        self.unix
+    }
+
+    #[inline]
+    ///Returns timestamp shifted by specified number of seconds.
+    pub const fn add_secs(&self, secs: i64) -> Self {
+        Self::from_unix(self.unix + secs)
+    }
+
+    ///Returns date components `(year, month, day)`.
+    pub const fn date(&self) -> (i64, u32, u32) {
+        civil_from_days(self.unix.div_euclid(SECS_PER_DAY))
+    }
+
+    ///Returns time components `(hour, minute, second)`.
+    pub const fn time(&self) -> (u32, u32, u32) {
+        let secs = self.unix.rem_euclid(SECS_PER_DAY) as u32;
+        (secs / 3_600, secs % 3_600 / 60, secs % 60)
+    }
+
+    #[inline]
+    ///Returns RFC 2822 representation (e.g. `Wed, 18 Aug 2010 20:01:40 +0000`)
+    pub const fn rfc2822(&self) -> Rfc2822 {
+        Rfc2822(*self)
+    }
+
+    ///Parses ISO 8601 timestamp (e.g. `2021-11-30T20:36:27Z` or `2021-11-30T21:36:27.000+01:00`)
+    pub fn parse_iso8601(text: &str) -> Result<Self, DateTimeParseError> {
+        let text = text.trim();
+        let bytes = text.as_bytes();
+        if !text.is_ascii() || bytes.len() < 19 || bytes[4] != b'-' || bytes[7] != b'-' || !(bytes[10] == b'T' || bytes[10] == b't' || bytes[10] == b' ') || bytes[13] != b':' || bytes[16] != b':' {
+            return Err(DateTimeParseError);
+        }
+
+        let year = parse_num(&text[0..4])?;
+        let month = parse_num(&text[5..7])?;
+        let day = parse_num(&text[8..10])?;
+        let hour = parse_num(&text[11..13])?;
+        let minute = parse_num(&text[14..16])?;
+        let second = parse_num(&text[17..19])?;
+
+        let mut rest = &text[19..];
+        if let Some(fraction) = rest.strip_prefix('.') {
+            let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return Err(DateTimeParseError);
+            }
+            rest = &fraction[digits..];
+        }
+
+        let offset = match rest {
+            "Z" | "z" => 0,
+            _ => parse_offset(rest)?,
+        };
+
+        match Self::from_utc(year as i64, month, day, hour, minute, second) {
+            Some(result) => Ok(result.add_secs(-offset)),
+            None => Err(DateTimeParseError),
+        }
+    }
+
+    ///Parses RFC 2822 timestamp (e.g. `Wed, 18 Aug 2010 20:01:40 +0000`)
+    pub fn parse_rfc2822(text: &str) -> Result<Self, DateTimeParseError> {
+        let text = text.trim();
+        //Day of week is optional and redundant.
+        let text = match text.find(',') {
+            Some(idx) => &text[idx + 1..],
+            None => text,
+        };
+
+        let mut parts = text.split_whitespace();
+        let day = parse_num(parts.next().ok_or(DateTimeParseError)?)?;
+        let month = parts.next().ok_or(DateTimeParseError)?;
+        let month = match MONTHS.iter().position(|name| name.eq_ignore_ascii_case(month)) {
+            Some(idx) => idx as u32 + 1,
+            None => return Err(DateTimeParseError),
+        };
+        let year = parse_num(parts.next().ok_or(DateTimeParseError)?)?;
+
+        let mut time = parts.next().ok_or(DateTimeParseError)?.split(':');
+        let hour = parse_num(time.next().ok_or(DateTimeParseError)?)?;
+        let minute = parse_num(time.next().ok_or(DateTimeParseError)?)?;
+        let second = match time.next() {
+            Some(second) => parse_num(second)?,
+            None => 0,
+        };
+        if time.next().is_some() {
+            return Err(DateTimeParseError);
+        }
+
+        let offset = match parts.next() {
+            Some("GMT") | Some("UT") | Some("UTC") | Some("Z") => 0,
+            Some(zone) => parse_offset(zone)?,
+            None => return Err(DateTimeParseError),
+        };
+        if parts.next().is_some() {
+            return Err(DateTimeParseError);
+        }
+
+        match Self::from_utc(year as i64, month, day, hour, minute, second) {
+            Some(result) => Ok(result.add_secs(-offset)),
+            None => Err(DateTimeParseError),
+        }
+    }
+}
+
+fn parse_num(text: &str) -> Result<u32, DateTimeParseError> {
+    if text.is_empty() || text.len() > 4 || !text.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(DateTimeParseError);
+    }
+
+    text.parse().map_err(|_| DateTimeParseError)
+}
+
+//Parses `+hhmm` or `+hh:mm` into number of seconds.
+fn parse_offset(text: &str) -> Result<i64, DateTimeParseError> {
+    if !text.is_ascii() {
+        return Err(DateTimeParseError);
+    }
+
+    let (sign, text) = match text.as_bytes().first() {
+        Some(b'+') => (1, &text[1..]),
+        Some(b'-') => (-1, &text[1..]),
+        _ => return Err(DateTimeParseError),
+    };
+
+    let (hours, minutes) = match text.len() {
+        4 => (&text[..2], &text[2..]),
+        5 if text.as_bytes()[2] == b':' => (&text[..2], &text[3..]),
+        _ => return Err(DateTimeParseError),
+    };
+
+    let hours = parse_num(hours)? as i64;
+    let minutes = parse_num(minutes)? as i64;
+    if hours > 23 || minutes > 59 {
+        return Err(DateTimeParseError);
+    }
+
+    Ok(sign * (hours * 3_600 + minutes * 60))
+}
+
+const fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 => match (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 {
+            true => 29,
+            false => 28,
+        },
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for TwilioDateTime {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.date();
+        let (hour, minute, second) = self.time();
+        fmt.write_fmt(format_args!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, hour, minute, second))
+    }
+}
+
+impl FromStr for TwilioDateTime {
+    type Err = DateTimeParseError;
+
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_iso8601(text).or_else(|_| Self::parse_rfc2822(text))
+    }
+}
+
+impl Serialize for TwilioDateTime {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.rfc2822())
+    }
+}
+
+struct TwilioDateTimeVisitor;
+
+impl<'de> Visitor<'de> for TwilioDateTimeVisitor {
+    type Value = TwilioDateTime;
+
+    #[inline(always)]
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("RFC 2822 or ISO 8601 timestamp")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+        text.parse().map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for TwilioDateTime {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(TwilioDateTimeVisitor)
+    }
+}
+
+///RFC 2822 representation of `TwilioDateTime`
+pub struct Rfc2822(TwilioDateTime);
+
+impl fmt::Display for Rfc2822 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.0.date();
+        let (hour, minute, second) = self.0.time();
+        let weekday = WEEKDAYS[self.0.unix.div_euclid(SECS_PER_DAY).rem_euclid(7) as usize];
+        fmt.write_fmt(format_args!("{}, {:02} {} {:04} {:02}:{:02}:{:02} +0000", weekday, day, MONTHS[month as usize - 1], year, hour, minute, second))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error parsing `TwilioDateTime`
+pub struct DateTimeParseError;
+
+impl fmt::Display for DateTimeParseError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("Invalid RFC 2822 or ISO 8601 timestamp")
+    }
+}
+
+impl std::error::Error for DateTimeParseError {
+}
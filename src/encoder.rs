@@ -32,3 +32,55 @@ pub fn format_pair(key: &str, value: &str, fmt: &mut fmt::Formatter) -> fmt::Res
 
     Ok(())
 }
+
+//Writes `text` as percent-encoded path segment.
+//
+//`+` is kept as it is, since it is not special within path.
+pub fn push_path_segment(text: &str, out: &mut String) {
+    use core::fmt::Write;
+
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'+' => out.push(byte as char),
+            byte => {
+                let _ = write!(out, "%{:02X}", byte);
+            },
+        }
+    }
+}
+
+//Writes `text` as JSON string literal.
+pub fn push_json_str(text: &str, out: &mut String) {
+    use core::fmt::Write;
+
+    out.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", ch as u32);
+            },
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
+//Writes JSON object with string values.
+pub fn json_object<'a, I: IntoIterator<Item = (&'a str, &'a str)>>(pairs: I) -> String {
+    let mut out = String::from("{");
+    for (key, value) in pairs {
+        if out.len() > 1 {
+            out.push(',');
+        }
+        push_json_str(key, &mut out);
+        out.push(':');
+        push_json_str(value, &mut out);
+    }
+    out.push('}');
+    out
+}
@@ -0,0 +1,148 @@
+//! Twilio API host configuration.
+
+use super::{UrlBuffer, REST_API_SMS_ENDPOINT, REST_API_CALL_ENDPOINT};
+
+///Default region, used by Twilio when only edge is specified.
+pub const DEFAULT_REGION: &str = "us1";
+///Path of v2010 REST API, relative to host.
+pub const REST_API_PATH: &str = "/2010-04-01/Accounts";
+//Length of the longest product subdomain, see `Product`.
+const MAX_PRODUCT_LEN: usize = "conversations".len();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Twilio API host.
+///
+///By default, global host `api.twilio.com` is used.
+///
+///When region or edge is specified, host is `api.<edge>.<region>.twilio.com`.
+///If only edge is specified, region is `us1`.
+///
+///Custom host replaces scheme and host completely, e.g. `http://127.0.0.1:8080` to use mock server.
+///Its length is limited by `Host::MAX_CUSTOM_LEN`.
+pub struct Host<'a> {
+    region: Option<&'a str>,
+    edge: Option<&'a str>,
+    custom: Option<&'a str>,
+}
+
+impl<'a> Host<'a> {
+    ///Global host `api.twilio.com`
+    pub const GLOBAL: Host<'static> = Host::new();
+    ///Maximum length of host name (including product subdomain).
+    ///
+    ///This is enough for `<product>.<edge>.<region>.twilio.com` with all known products, edges and regions.
+    pub const MAX_HOST_LEN: usize = 48;
+    ///Maximum length of custom host, including scheme.
+    pub const MAX_CUSTOM_LEN: usize = "https://".len() + Self::MAX_HOST_LEN;
+
+    #[inline]
+    ///Creates global host.
+    pub const fn new() -> Self {
+        Self {
+            region: None,
+            edge: None,
+            custom: None,
+        }
+    }
+
+    #[inline]
+    ///Creates custom host, which must include scheme.
+    pub const fn custom(base: &'a str) -> Self {
+        assert!(base.len() <= Self::MAX_CUSTOM_LEN, "Custom host is too long");
+        Self {
+            region: None,
+            edge: None,
+            custom: Some(base),
+        }
+    }
+
+    #[inline]
+    ///Sets region (e.g. `ie1` or `au1`).
+    ///
+    ///Panics if resulting host exceeds `Host::MAX_HOST_LEN`.
+    pub const fn region(mut self, region: &'a str) -> Self {
+        self.region = Some(region);
+        assert!(self.host_len() <= Self::MAX_HOST_LEN, "Region is too long");
+        self
+    }
+
+    #[inline]
+    ///Sets edge location (e.g. `dublin` or `sydney`).
+    ///
+    ///Panics if resulting host exceeds `Host::MAX_HOST_LEN`.
+    pub const fn edge(mut self, edge: &'a str) -> Self {
+        self.edge = Some(edge);
+        assert!(self.host_len() <= Self::MAX_HOST_LEN, "Edge is too long");
+        self
+    }
+
+    //Length of the longest possible host name, which is host of product with the longest subdomain.
+    const fn host_len(&self) -> usize {
+        let location = match (self.edge, self.region) {
+            (Some(edge), Some(region)) => edge.len() + 1 + region.len() + 1,
+            (Some(edge), None) => edge.len() + 1 + DEFAULT_REGION.len() + 1,
+            (None, Some(region)) => region.len() + 1,
+            (None, None) => 0,
+        };
+        MAX_PRODUCT_LEN + 1 + location + "twilio.com".len()
+    }
+
+    ///Appends scheme and host of `product` (e.g. `api`) to the `buffer`.
+    pub(crate) const fn write_base<const N: usize>(&self, product: &str, buffer: str_buf::StrBuf<N>) -> str_buf::StrBuf<N> {
+        if let Some(custom) = self.custom {
+            return buffer.and(custom);
+        }
+
+        let buffer = buffer.and("https://").and(product).and(".");
+        let buffer = match (self.edge, self.region) {
+            (Some(edge), Some(region)) => buffer.and(edge).and(".").and(region).and("."),
+            (Some(edge), None) => buffer.and(edge).and(".").and(DEFAULT_REGION).and("."),
+            (None, Some(region)) => buffer.and(region).and("."),
+            (None, None) => buffer,
+        };
+        buffer.and("twilio.com")
+    }
+
+    #[inline]
+    ///Creates base URL of REST API resources for `account_sid`
+    ///
+    ///Resulting URL has no trailing slash: `<host>/2010-04-01/Accounts/<account_sid>`
+    pub const fn account_url(&self, account_sid: &str) -> UrlBuffer {
+        self.write_base("api", UrlBuffer::new()).and(REST_API_PATH).and("/").and(account_sid)
+    }
+
+    #[inline]
+    ///Creates base URL to fetch SMS onto.
+    ///
+    ///To fetch SMS you need to call `<base>/<id>.json`
+    pub const fn get_sms_base(&self, account_sid: &str) -> UrlBuffer {
+        self.account_url(account_sid).and("/").and("Messages")
+    }
+
+    #[inline]
+    ///Creates URL to post SMS onto or fetch multiple SMS
+    pub const fn sms_resource_url(&self, account_sid: &str) -> UrlBuffer {
+        self.account_url(account_sid).and("/").and(REST_API_SMS_ENDPOINT)
+    }
+
+    #[inline]
+    ///Creates base URL to fetch Call onto.
+    ///
+    ///To fetch Call you need to call `<base>/<id>.json`
+    pub const fn get_call_base(&self, account_sid: &str) -> UrlBuffer {
+        self.account_url(account_sid).and("/").and("Calls")
+    }
+
+    #[inline]
+    ///Creates URL to post Call onto or fetch multiple Call
+    pub const fn call_resource_url(&self, account_sid: &str) -> UrlBuffer {
+        self.account_url(account_sid).and("/").and(REST_API_CALL_ENDPOINT)
+    }
+}
+
+impl Default for Host<'_> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
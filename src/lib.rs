@@ -3,15 +3,97 @@
 //! To be used as building blocks.
 
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::needless_lifetimes))]
+#![allow(clippy::style, clippy::needless_lifetimes)]
 
 use core::fmt::{self, Write};
+use std::collections::HashMap;
 
 use serde::Deserialize;
 
+//Implements conversion from/to string for enum with `Unknown(String)` fallback.
+macro_rules! impl_str_enum {
+    ($name:ident { $($variant:ident => $text:literal,)+ }) => {
+        impl $name {
+            ///Returns textual representation, as used by Twilio.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $text,)+
+                    $name::Unknown(text) => text.as_str(),
+                }
+            }
+        }
+
+        impl core::str::FromStr for $name {
+            type Err = core::convert::Infallible;
+
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                match text {
+                    $($text => Ok($name::$variant),)+
+                    _ => Ok($name::Unknown(text.to_owned())),
+                }
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            #[inline(always)]
+            fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+                fmt.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            #[inline]
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let text = <std::borrow::Cow<'de, str> as serde::Deserialize>::deserialize(deserializer)?;
+                match text.parse() {
+                    Ok(result) => Ok(result),
+                    Err(never) => match never {},
+                }
+            }
+        }
+    };
+}
+
 mod encoder;
 mod ser;
+mod retry;
+mod rate;
+mod auth;
+mod host;
+mod path;
+mod datetime;
+mod price;
+mod status;
+mod recording;
+mod conference;
+mod queue;
+mod phone_number;
+pub mod lookup;
+pub mod verify;
+pub mod messaging;
+pub mod content;
+
+pub use retry::{RetryPolicy, RetryDecision, RETRY_AFTER_HEADER, TOO_MANY_REQUESTS_CODE};
+pub use rate::{RateLimiter, SenderThroughput, SendSlot, ValidityExceeded, DEFAULT_VALIDITY_PERIOD};
+pub use auth::{Credentials, CredentialsError, ACCOUNT_SID_ENV, AUTH_TOKEN_ENV, API_KEY_ENV, API_SECRET_ENV};
+pub use host::{Host, DEFAULT_REGION, REST_API_PATH};
+pub use path::{ResourcePath, Resource, ResourceUrl, ProductPath, Product};
+pub use datetime::{TwilioDateTime, Rfc2822, DateTimeParseError};
+pub use price::{Decimal, Currency, Price, CostLedger, PriceParseError};
+pub use status::{Lifecycle, StatusTracker, SmsStatusCallback, CallStatusCallback};
+pub use recording::{Recording, RecordingList, RecordingStatus, RecordingSource, RecordingFormat, EncryptionDetails, RecordingStatusCallback, recording_media_url};
+pub use recording::{CallRecording, CallRecordingUpdate, RecordingUpdateStatus, PauseBehavior, RecordingChannels, RecordingTrack, RecordingStatusCallbackEvent, CURRENT_RECORDING};
+pub use conference::{Conference, ConferenceList, ConferenceStatus, ConferenceUpdate, ConferenceUpdateStatus, Participant, ParticipantList, ParticipantStatus, ParticipantCreate, ParticipantUpdate, Beep, ConferenceEvent, ConferenceStatusCallback};
+pub use queue::{Queue, QueueList, QueueCreate, QueueMember, QueueMemberList, QueueDequeue, QueueResult, EnqueueWaitCallback, EnqueueActionCallback, FRONT_MEMBER};
+pub use phone_number::{PhoneNumber, InvalidPhoneNumber, PhoneNumberType, NumberPattern, InvalidNumberPattern, LatLong, AvailablePhoneNumberSearch, AvailablePhoneNumber, AvailablePhoneNumberList, PhoneNumberCapabilities};
+pub use phone_number::{IncomingPhoneNumber, IncomingPhoneNumberList, PhoneNumberConfig, IncomingPhoneNumberCreate, IncomingPhoneNumberUpdate};
 
 ///Twilio REST API base url
 pub const REST_API_URL: &str = "api.twilio.com/2010-04-01/Accounts";
@@ -21,35 +103,44 @@ pub const REST_API_SMS_ENDPOINT: &str = "Messages.json";
 pub const REST_API_CALL_ENDPOINT: &str = "Calls.json";
 
 //Fetch SMS link probably max
-//https://api.twilio.com/2010-04-01/Accounts/XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages.json
-const URL_BUFFER_SIZE: usize = 92;
+//https://api.<edge>.<region>.twilio.com/2010-04-01/Accounts/XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages.json
+const URL_BUFFER_SIZE: usize = Host::MAX_CUSTOM_LEN + host::REST_API_PATH.len() + 1 + 34 + 1 + REST_API_SMS_ENDPOINT.len();
 ///URL storage for `const fn` creation.
 pub type UrlBuffer = str_buf::StrBuf<URL_BUFFER_SIZE>;
 
 ///Creates base URL to fetch SMS onto.
 ///
 ///To fetch SMS you need to call `<base>/<id>.json`
+///
+///Uses global host, see `Host` for other options.
 pub const fn get_sms_base(account_sid: &str) -> UrlBuffer {
-    UrlBuffer::new().and("https://").and(REST_API_URL).and("/").and(account_sid).and("/").and("Messages")
+    Host::GLOBAL.get_sms_base(account_sid)
 }
 
 ///Creates URL to post SMS onto or fetch multiple SMS
+///
+///Uses global host, see `Host` for other options.
 pub const fn sms_resource_url(account_sid: &str) -> UrlBuffer {
-    UrlBuffer::new().and("https://").and(REST_API_URL).and("/").and(account_sid).and("/").and(REST_API_SMS_ENDPOINT)
+    Host::GLOBAL.sms_resource_url(account_sid)
 }
 
 ///Creates base URL to fetch Call onto.
 ///
 ///To fetch Call you need to call `<base>/<id>.json`
+///
+///Uses global host, see `Host` for other options.
 pub const fn get_call_base(account_sid: &str) -> UrlBuffer {
-    UrlBuffer::new().and("https://").and(REST_API_URL).and("/").and(account_sid).and("/").and("Calls")
+    Host::GLOBAL.get_call_base(account_sid)
 }
 
 ///Creates URL to post Call onto or fetch multiple Call
+///
+///Uses global host, see `Host` for other options.
 pub const fn call_resource_url(account_sid: &str) -> UrlBuffer {
-    UrlBuffer::new().and("https://").and(REST_API_URL).and("/").and(account_sid).and("/").and(REST_API_CALL_ENDPOINT)
+    Host::GLOBAL.call_resource_url(account_sid)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 ///Describes possible http methods, twilio can use to invoke callback.
 pub enum TwilioMethod {
     ///Get
@@ -74,6 +165,31 @@ impl Default for TwilioMethod {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error scheduling message.
+pub enum ScheduleError {
+    ///`MessagingServiceSid` is not set.
+    MissingMessagingService,
+    ///Time of message is less than 15 minutes from now.
+    TooEarly,
+    ///Time of message is more than 35 days from now.
+    TooLate,
+}
+
+impl fmt::Display for ScheduleError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScheduleError::MissingMessagingService => fmt.write_str("Scheduled message requires MessagingServiceSid"),
+            ScheduleError::TooEarly => fmt.write_str("Message must be scheduled at least 15 minutes in advance"),
+            ScheduleError::TooLate => fmt.write_str("Message cannot be scheduled more than 35 days in advance"),
+        }
+    }
+}
+
+impl std::error::Error for ScheduleError {
+}
+
 ///Generic Twilio request builder.
 ///
 ///Data is encoded as `application/x-www-form-urlencode`.
@@ -86,6 +202,7 @@ impl Default for TwilioMethod {
 pub struct TwilioRequest {
     buffer: Vec<u8>,
     len: usize,
+    idempotency_token: Option<String>,
 }
 
 impl TwilioRequest {
@@ -93,15 +210,43 @@ impl TwilioRequest {
     ///
     ///To be used for HTTP Post requests
     pub const CONTENT_TYPE: &'static str = "application/x-www-form-urlencode";
+    ///Minimum number of seconds between now and time of scheduled message.
+    pub const SCHEDULE_MIN_SECS: i64 = 15 * 60;
+    ///Maximum number of seconds between now and time of scheduled message.
+    pub const SCHEDULE_MAX_SECS: i64 = 35 * 24 * 60 * 60;
+    ///HTTP header to carry idempotency token.
+    pub const IDEMPOTENCY_TOKEN_HEADER: &'static str = "I-Twilio-Idempotency-Token";
 
     ///Creates new request.
     pub const fn new() -> Self {
         Self {
             buffer: Vec::new(),
-            len: 0
+            len: 0,
+            idempotency_token: None,
         }
     }
 
+    #[inline]
+    ///Sets idempotency token, marking request as safe to retry.
+    ///
+    ///Token is not part of form data and should be sent via `IDEMPOTENCY_TOKEN_HEADER`.
+    pub fn idempotency_token(&mut self, token: &str) -> &mut Self {
+        self.idempotency_token = Some(token.to_owned());
+        self
+    }
+
+    #[inline]
+    ///Returns idempotency token, if any.
+    pub fn get_idempotency_token(&self) -> Option<&str> {
+        self.idempotency_token.as_deref()
+    }
+
+    #[inline]
+    ///Returns whether request is safe to retry.
+    pub fn is_idempotent(&self) -> bool {
+        self.idempotency_token.is_some()
+    }
+
     #[inline]
     ///Returns raw `application/x-www-form-urlencoded` data.
     pub fn into_bytes(self) -> Vec<u8> {
@@ -130,6 +275,27 @@ impl TwilioRequest {
         self
     }
 
+    #[inline]
+    fn add_bool(&mut self, field: &str, value: bool) -> &mut Self {
+        match value {
+            true => self.add_pair(field, "true"),
+            false => self.add_pair(field, "false"),
+        }
+    }
+
+    fn add_nested(&mut self, parent: &str, field: &str, value: &str) -> &mut Self {
+        let mut name = String::with_capacity(parent.len() + 1 + field.len());
+        name.push_str(parent);
+        name.push('.');
+        name.push_str(field);
+        self.add_pair(&name, value)
+    }
+
+    ///Returns decoded value of first occurrence of `field`, if any.
+    pub fn get_field(&self, field: &str) -> Option<std::borrow::Cow<'_, str>> {
+        form_urlencoded::parse(&self.buffer).find(|(key, _)| key == field).map(|(_, value)| value)
+    }
+
     #[inline]
     ///Adds `AccountSid` to specify owner of the resource.
     pub fn account_sid(&mut self, sid: &str) -> &mut Self {
@@ -152,6 +318,20 @@ impl TwilioRequest {
         self.add_pair("To", to)
     }
 
+    #[inline]
+    ///Adds `MessagingServiceSid` field, which is identifier of messaging service to send message from.
+    ///
+    ///Can be used instead of `From`
+    pub fn messaging_service_sid(&mut self, sid: &str) -> &mut Self {
+        self.add_pair("MessagingServiceSid", sid)
+    }
+
+    #[inline]
+    ///Adds either `From` or `MessagingServiceSid` field, depending on `sender`.
+    pub fn sender(&mut self, sender: Sender<'_>) -> &mut Self {
+        self.add_pair(sender.field(), sender.as_str())
+    }
+
     #[inline]
     ///Adds `Body` field.
     pub fn body(&mut self, body: &str) -> &mut Self {
@@ -200,10 +380,34 @@ impl TwilioRequest {
 
     #[inline]
     ///Sets `SendAt` field, to indicate where message is to be sent.
+    ///
+    ///Prefer `schedule` which validates time and sets `ScheduleType`.
     pub fn send_at(&mut self, date: &str) -> &mut Self {
         self.add_pair("SendAt", date)
     }
 
+    ///Schedules message to be sent at `send_at`, setting `SendAt` and `ScheduleType` fields.
+    ///
+    ///`send_at` must be within `SCHEDULE_MIN_SECS` and `SCHEDULE_MAX_SECS` from `now`.
+    ///
+    ///Scheduling requires `MessagingServiceSid` to be set beforehand.
+    pub fn schedule(&mut self, send_at: TwilioDateTime, now: TwilioDateTime) -> Result<&mut Self, ScheduleError> {
+        if self.get_field("MessagingServiceSid").is_none() {
+            return Err(ScheduleError::MissingMessagingService);
+        }
+
+        let delay = send_at.unix() - now.unix();
+        if delay < Self::SCHEDULE_MIN_SECS {
+            return Err(ScheduleError::TooEarly);
+        } else if delay > Self::SCHEDULE_MAX_SECS {
+            return Err(ScheduleError::TooLate);
+        }
+
+        let mut buf = str_buf::StrBuf::<32>::new();
+        let _ = write!(buf, "{}", send_at);
+        Ok(self.add_pair("SendAt", buf.as_str()).add_pair("ScheduleType", "fixed"))
+    }
+
     #[inline]
     ///Sets `Twiml` field, to provide call's content as xml string.
     pub fn twiml(&mut self, twiml: &str) -> &mut Self {
@@ -245,6 +449,490 @@ impl TwilioRequest {
         self.add_pair("CallerId", id)
     }
 
+    #[inline]
+    ///Sets `Status` field, to modify live call.
+    pub fn call_status(&mut self, status: CallUpdateStatus) -> &mut Self {
+        self.add_pair("Status", status.as_str())
+    }
+
+    #[inline]
+    ///Sets `TimeLimit` field, to provide maximum duration of call in seconds.
+    pub fn time_limit(&mut self, limit: u32) -> &mut Self {
+        let mut buf = str_buf::StrBuf::<10>::new();
+        let _ = write!(buf, "{}", limit);
+        self.add_pair("TimeLimit", buf.as_str())
+    }
+
+    #[inline]
+    ///Sets `RecordingChannels` field, to provide number of channels in recording.
+    pub fn recording_channels(&mut self, channels: RecordingChannels) -> &mut Self {
+        self.add_pair("RecordingChannels", channels.as_str())
+    }
+
+    #[inline]
+    ///Sets `RecordingTrack` field, to provide audio track to record.
+    pub fn recording_track(&mut self, track: RecordingTrack) -> &mut Self {
+        self.add_pair("RecordingTrack", track.as_str())
+    }
+
+    #[inline]
+    ///Sets `RecordingStatusCallback` field, to provide URL where to post recording status information.
+    pub fn recording_status_callback(&mut self, url: &str) -> &mut Self {
+        self.add_pair("RecordingStatusCallback", url)
+    }
+
+    ///Sets `RecordingStatusCallbackEvent` field, to provide events to post recording status information on.
+    ///
+    ///Duplicate events are ignored.
+    pub fn recording_status_callback_event(&mut self, events: &[RecordingStatusCallbackEvent]) -> &mut Self {
+        let mut value = String::new();
+        for (idx, event) in events.iter().enumerate() {
+            if events[..idx].contains(event) {
+                continue;
+            }
+            if !value.is_empty() {
+                value.push(' ');
+            }
+            value.push_str(event.as_str());
+        }
+        self.add_pair("RecordingStatusCallbackEvent", &value)
+    }
+
+    #[inline]
+    ///Sets `Status` field, to modify live recording.
+    pub fn recording_status(&mut self, status: RecordingUpdateStatus) -> &mut Self {
+        self.add_pair("Status", status.as_str())
+    }
+
+    #[inline]
+    ///Sets `PauseBehavior` field, to provide way to handle paused part of recording.
+    pub fn pause_behavior(&mut self, behavior: PauseBehavior) -> &mut Self {
+        self.add_pair("PauseBehavior", behavior.as_str())
+    }
+
+    #[inline]
+    ///Sets `Status` field, to end conference.
+    pub fn conference_status(&mut self, status: ConferenceUpdateStatus) -> &mut Self {
+        self.add_pair("Status", status.as_str())
+    }
+
+    #[inline]
+    ///Sets `AnnounceUrl` field, to provide URL of audio or TwiML to announce.
+    pub fn announce_url(&mut self, url: &str) -> &mut Self {
+        self.add_pair("AnnounceUrl", url)
+    }
+
+    #[inline]
+    ///Sets `Label` field, to provide label of conference participant.
+    pub fn label(&mut self, label: &str) -> &mut Self {
+        self.add_pair("Label", label)
+    }
+
+    #[inline]
+    ///Sets `EarlyMedia` field, to specify whether ringing audio is connected before answer.
+    pub fn early_media(&mut self, value: bool) -> &mut Self {
+        self.add_bool("EarlyMedia", value)
+    }
+
+    #[inline]
+    ///Sets `Beep` field, to specify when to play beep in conference.
+    pub fn beep(&mut self, beep: Beep) -> &mut Self {
+        self.add_pair("Beep", beep.as_str())
+    }
+
+    #[inline]
+    ///Sets `Muted` field, to specify whether conference participant is muted.
+    pub fn muted(&mut self, value: bool) -> &mut Self {
+        self.add_bool("Muted", value)
+    }
+
+    #[inline]
+    ///Sets `Hold` field, to specify whether conference participant is on hold.
+    pub fn hold(&mut self, value: bool) -> &mut Self {
+        self.add_bool("Hold", value)
+    }
+
+    #[inline]
+    ///Sets `HoldUrl` field, to provide URL of audio or TwiML to play on hold.
+    pub fn hold_url(&mut self, url: &str) -> &mut Self {
+        self.add_pair("HoldUrl", url)
+    }
+
+    #[inline]
+    ///Sets `Coaching` field, to specify whether conference participant is coaching.
+    pub fn coaching(&mut self, value: bool) -> &mut Self {
+        self.add_bool("Coaching", value)
+    }
+
+    #[inline]
+    ///Sets `CallSidToCoach` field, to provide ID of call to coach.
+    pub fn call_sid_to_coach(&mut self, sid: &str) -> &mut Self {
+        self.add_pair("CallSidToCoach", sid)
+    }
+
+    #[inline]
+    ///Sets `StartConferenceOnEnter` field, to specify whether conference starts when participant joins.
+    pub fn start_conference_on_enter(&mut self, value: bool) -> &mut Self {
+        self.add_bool("StartConferenceOnEnter", value)
+    }
+
+    #[inline]
+    ///Sets `EndConferenceOnExit` field, to specify whether conference ends when participant leaves.
+    pub fn end_conference_on_exit(&mut self, value: bool) -> &mut Self {
+        self.add_bool("EndConferenceOnExit", value)
+    }
+
+    #[inline]
+    ///Sets `FriendlyName` field, to provide human readable name of resource.
+    pub fn friendly_name(&mut self, name: &str) -> &mut Self {
+        self.add_pair("FriendlyName", name)
+    }
+
+    #[inline]
+    ///Sets `MaxSize` field, to provide maximum number of calls in queue.
+    pub fn max_size(&mut self, size: u32) -> &mut Self {
+        let mut buf = str_buf::StrBuf::<10>::new();
+        let _ = write!(buf, "{}", size);
+        self.add_pair("MaxSize", buf.as_str())
+    }
+
+    #[inline]
+    ///Sets `AreaCode` field, to filter phone numbers by area code.
+    pub fn area_code(&mut self, code: u16) -> &mut Self {
+        let mut buf = str_buf::StrBuf::<5>::new();
+        let _ = write!(buf, "{}", code);
+        self.add_pair("AreaCode", buf.as_str())
+    }
+
+    #[inline]
+    ///Sets `Contains` field, to filter phone numbers by pattern.
+    pub fn contains(&mut self, pattern: NumberPattern<'_>) -> &mut Self {
+        self.add_pair("Contains", pattern.as_str())
+    }
+
+    #[inline]
+    ///Sets `SmsEnabled` field, to filter phone numbers by SMS capability.
+    pub fn sms_enabled(&mut self, value: bool) -> &mut Self {
+        self.add_bool("SmsEnabled", value)
+    }
+
+    #[inline]
+    ///Sets `MmsEnabled` field, to filter phone numbers by MMS capability.
+    pub fn mms_enabled(&mut self, value: bool) -> &mut Self {
+        self.add_bool("MmsEnabled", value)
+    }
+
+    #[inline]
+    ///Sets `VoiceEnabled` field, to filter phone numbers by voice capability.
+    pub fn voice_enabled(&mut self, value: bool) -> &mut Self {
+        self.add_bool("VoiceEnabled", value)
+    }
+
+    #[inline]
+    ///Sets `InRegion` field, to filter phone numbers by region.
+    pub fn in_region(&mut self, region: &str) -> &mut Self {
+        self.add_pair("InRegion", region)
+    }
+
+    #[inline]
+    ///Sets `NearLatLong` field, to filter phone numbers by location.
+    pub fn near_lat_long(&mut self, location: LatLong) -> &mut Self {
+        let mut buf = str_buf::StrBuf::<64>::new();
+        let _ = write!(buf, "{}", location);
+        self.add_pair("NearLatLong", buf.as_str())
+    }
+
+    #[inline]
+    ///Sets `Distance` field, to provide search radius in miles around `NearLatLong`.
+    pub fn distance(&mut self, miles: u16) -> &mut Self {
+        let mut buf = str_buf::StrBuf::<5>::new();
+        let _ = write!(buf, "{}", miles);
+        self.add_pair("Distance", buf.as_str())
+    }
+
+    #[inline]
+    ///Sets `ExcludeAllAddressRequired` field, to exclude phone numbers requiring address.
+    pub fn exclude_all_address_required(&mut self, value: bool) -> &mut Self {
+        self.add_bool("ExcludeAllAddressRequired", value)
+    }
+
+    #[inline]
+    ///Sets `PhoneNumber` field, to provide phone number.
+    pub fn phone_number(&mut self, number: &str) -> &mut Self {
+        self.add_pair("PhoneNumber", number)
+    }
+
+    #[inline]
+    ///Sets `SmsUrl` field, to provide URL to invoke on incoming message.
+    pub fn sms_url(&mut self, url: &str) -> &mut Self {
+        self.add_pair("SmsUrl", url)
+    }
+
+    #[inline]
+    ///Sets `SmsUrl` field, to provide URL to invoke on incoming message.
+    ///
+    ///With option of setting HTTP method to access URL.
+    pub fn sms_url_with_method(&mut self, method: TwilioMethod, url: &str) -> &mut Self {
+        self.add_pair("SmsMethod", method.as_str()).add_pair("SmsUrl", url)
+    }
+
+    #[inline]
+    ///Sets `SmsFallbackUrl` field, to provide URL to invoke when `SmsUrl` fails.
+    pub fn sms_fallback_url(&mut self, url: &str) -> &mut Self {
+        self.add_pair("SmsFallbackUrl", url)
+    }
+
+    #[inline]
+    ///Sets `SmsFallbackUrl` field, to provide URL to invoke when `SmsUrl` fails.
+    ///
+    ///With option of setting HTTP method to access URL.
+    pub fn sms_fallback_url_with_method(&mut self, method: TwilioMethod, url: &str) -> &mut Self {
+        self.add_pair("SmsFallbackMethod", method.as_str()).add_pair("SmsFallbackUrl", url)
+    }
+
+    #[inline]
+    ///Sets `VoiceUrl` field, to provide URL to invoke on incoming call.
+    pub fn voice_url(&mut self, url: &str) -> &mut Self {
+        self.add_pair("VoiceUrl", url)
+    }
+
+    #[inline]
+    ///Sets `VoiceUrl` field, to provide URL to invoke on incoming call.
+    ///
+    ///With option of setting HTTP method to access URL.
+    pub fn voice_url_with_method(&mut self, method: TwilioMethod, url: &str) -> &mut Self {
+        self.add_pair("VoiceMethod", method.as_str()).add_pair("VoiceUrl", url)
+    }
+
+    #[inline]
+    ///Sets `VoiceFallbackUrl` field, to provide URL to invoke when `VoiceUrl` fails.
+    pub fn voice_fallback_url(&mut self, url: &str) -> &mut Self {
+        self.add_pair("VoiceFallbackUrl", url)
+    }
+
+    #[inline]
+    ///Sets `VoiceFallbackUrl` field, to provide URL to invoke when `VoiceUrl` fails.
+    ///
+    ///With option of setting HTTP method to access URL.
+    pub fn voice_fallback_url_with_method(&mut self, method: TwilioMethod, url: &str) -> &mut Self {
+        self.add_pair("VoiceFallbackMethod", method.as_str()).add_pair("VoiceFallbackUrl", url)
+    }
+
+    ///Sets `Fields` field, to provide data packages of phone number lookup.
+    pub fn lookup_fields(&mut self, fields: &[lookup::LookupField]) -> &mut Self {
+        let mut buf = String::new();
+        for field in fields.iter() {
+            if !buf.is_empty() {
+                buf.push(',');
+            }
+            buf.push_str(field.as_str());
+        }
+        self.add_pair("Fields", &buf)
+    }
+
+    #[inline]
+    ///Sets `CountryCode` field, to provide ISO country code of phone number in national format.
+    pub fn country_code(&mut self, code: &str) -> &mut Self {
+        self.add_pair("CountryCode", code)
+    }
+
+    #[inline]
+    ///Sets `Channel` field, to provide channel of verification.
+    pub fn channel(&mut self, channel: &verify::VerificationChannel) -> &mut Self {
+        self.add_pair("Channel", channel.as_str())
+    }
+
+    #[inline]
+    ///Sets `Locale` field, to provide language of verification message.
+    pub fn locale(&mut self, locale: &str) -> &mut Self {
+        self.add_pair("Locale", locale)
+    }
+
+    #[inline]
+    ///Sets `CustomFriendlyName` field, to override name of verification service in message.
+    pub fn custom_friendly_name(&mut self, name: &str) -> &mut Self {
+        self.add_pair("CustomFriendlyName", name)
+    }
+
+    #[inline]
+    ///Sets `RateLimits` field, to provide values of verification service's rate limits.
+    ///
+    ///Pairs of rate limit's unique name and value are encoded as JSON object.
+    pub fn rate_limits(&mut self, limits: &[(&str, &str)]) -> &mut Self {
+        let json = encoder::json_object(limits.iter().map(|(key, value)| (*key, *value)));
+        self.add_pair("RateLimits", &json)
+    }
+
+    #[inline]
+    ///Sets `Status` field, to modify verification.
+    pub fn verification_status(&mut self, status: verify::VerificationUpdateStatus) -> &mut Self {
+        self.add_pair("Status", status.as_str())
+    }
+
+    #[inline]
+    ///Sets `VerificationSid` field, to provide ID of verification.
+    pub fn verification_sid(&mut self, sid: &str) -> &mut Self {
+        self.add_pair("VerificationSid", sid)
+    }
+
+    #[inline]
+    ///Sets `Code` field, to provide verification code.
+    pub fn code(&mut self, code: &str) -> &mut Self {
+        self.add_pair("Code", code)
+    }
+
+    #[inline]
+    ///Sets `CodeLength` field, to provide number of digits in verification code.
+    pub fn code_length(&mut self, length: u8) -> &mut Self {
+        let mut buf = str_buf::StrBuf::<3>::new();
+        let _ = write!(buf, "{}", length);
+        self.add_pair("CodeLength", buf.as_str())
+    }
+
+    #[inline]
+    ///Sets `FactorType` field, to provide type of Verify factor.
+    pub fn factor_type(&mut self, factor_type: &verify::FactorType) -> &mut Self {
+        self.add_pair("FactorType", factor_type.as_str())
+    }
+
+    #[inline]
+    ///Sets `Binding.<field>` field, to provide binding of Verify factor.
+    pub fn binding(&mut self, field: &str, value: &str) -> &mut Self {
+        self.add_nested("Binding", field, value)
+    }
+
+    #[inline]
+    ///Sets `Config.<field>` field, to provide configuration of Verify factor.
+    pub fn config(&mut self, field: &str, value: &str) -> &mut Self {
+        self.add_nested("Config", field, value)
+    }
+
+    #[inline]
+    ///Sets `AuthPayload` field, to provide code of Verify factor.
+    pub fn auth_payload(&mut self, payload: &str) -> &mut Self {
+        self.add_pair("AuthPayload", payload)
+    }
+
+    #[inline]
+    ///Sets `FactorSid` field, to provide ID of Verify factor.
+    pub fn factor_sid(&mut self, sid: &str) -> &mut Self {
+        self.add_pair("FactorSid", sid)
+    }
+
+    #[inline]
+    ///Sets `ExpirationDate` field, to provide time when Verify challenge expires.
+    pub fn expiration_date(&mut self, date: TwilioDateTime) -> &mut Self {
+        let mut buf = str_buf::StrBuf::<32>::new();
+        let _ = write!(buf, "{}", date);
+        self.add_pair("ExpirationDate", buf.as_str())
+    }
+
+    #[inline]
+    ///Sets `Details.<field>` field, to provide details of Verify challenge.
+    pub fn details(&mut self, field: &str, value: &str) -> &mut Self {
+        self.add_nested("Details", field, value)
+    }
+
+    #[inline]
+    ///Sets `HiddenDetails` field, to provide details of Verify challenge not shown to user.
+    ///
+    ///Pairs of key and value are encoded as JSON object.
+    pub fn hidden_details(&mut self, details: &[(&str, &str)]) -> &mut Self {
+        let json = encoder::json_object(details.iter().map(|(key, value)| (*key, *value)));
+        self.add_pair("HiddenDetails", &json)
+    }
+
+    #[inline]
+    ///Sets `InboundRequestUrl` field, to provide URL to invoke on incoming message of messaging service.
+    pub fn inbound_request_url(&mut self, url: &str) -> &mut Self {
+        self.add_pair("InboundRequestUrl", url)
+    }
+
+    #[inline]
+    ///Sets `InboundRequestUrl` field, to provide URL to invoke on incoming message of messaging service.
+    ///
+    ///With option of setting HTTP method to access URL.
+    pub fn inbound_request_url_with_method(&mut self, method: TwilioMethod, url: &str) -> &mut Self {
+        self.add_pair("InboundMethod", method.as_str()).add_pair("InboundRequestUrl", url)
+    }
+
+    #[inline]
+    ///Sets `FallbackUrl` field, to provide URL to invoke when `InboundRequestUrl` fails.
+    pub fn fallback_url(&mut self, url: &str) -> &mut Self {
+        self.add_pair("FallbackUrl", url)
+    }
+
+    #[inline]
+    ///Sets `FallbackUrl` field, to provide URL to invoke when `InboundRequestUrl` fails.
+    ///
+    ///With option of setting HTTP method to access URL.
+    pub fn fallback_url_with_method(&mut self, method: TwilioMethod, url: &str) -> &mut Self {
+        self.add_pair("FallbackMethod", method.as_str()).add_pair("FallbackUrl", url)
+    }
+
+    #[inline]
+    ///Sets `StickySender` field, to use the same sender for each recipient.
+    pub fn sticky_sender(&mut self, value: bool) -> &mut Self {
+        self.add_bool("StickySender", value)
+    }
+
+    #[inline]
+    ///Sets `SmartEncoding` field, to replace Unicode characters with GSM-7 look-alikes.
+    pub fn smart_encoding(&mut self, value: bool) -> &mut Self {
+        self.add_bool("SmartEncoding", value)
+    }
+
+    #[inline]
+    ///Sets `AreaCodeGeomatch` field, to prefer sender with the same area code as recipient.
+    pub fn area_code_geomatch(&mut self, value: bool) -> &mut Self {
+        self.add_bool("AreaCodeGeomatch", value)
+    }
+
+    #[inline]
+    ///Sets `FallbackToLongCode` field, to send via long code when short code fails.
+    pub fn fallback_to_long_code(&mut self, value: bool) -> &mut Self {
+        self.add_bool("FallbackToLongCode", value)
+    }
+
+    #[inline]
+    ///Sets `MmsConverter` field, to convert MMS into SMS with media URL when MMS is unsupported.
+    pub fn mms_converter(&mut self, value: bool) -> &mut Self {
+        self.add_bool("MmsConverter", value)
+    }
+
+    #[inline]
+    ///Sets `PhoneNumberSid` field, to add phone number to sender pool.
+    pub fn phone_number_sid(&mut self, sid: &str) -> &mut Self {
+        self.add_pair("PhoneNumberSid", sid)
+    }
+
+    #[inline]
+    ///Sets `ShortCodeSid` field, to add short code to sender pool.
+    pub fn short_code_sid(&mut self, sid: &str) -> &mut Self {
+        self.add_pair("ShortCodeSid", sid)
+    }
+
+    #[inline]
+    ///Sets `AlphaSender` field, to add alphanumeric sender ID to sender pool.
+    pub fn alpha_sender(&mut self, sender: &str) -> &mut Self {
+        self.add_pair("AlphaSender", sender)
+    }
+
+    #[inline]
+    ///Sets `ContentSid` field, to send message using content template.
+    pub fn content_sid(&mut self, sid: &str) -> &mut Self {
+        self.add_pair("ContentSid", sid)
+    }
+
+    #[inline]
+    ///Sets `ContentVariables` field, to provide values of content template's placeholders.
+    ///
+    ///Pairs of placeholder (e.g. `1` for `{{1}}`) and value are encoded as JSON object.
+    pub fn content_variables(&mut self, variables: &[(&str, &str)]) -> &mut Self {
+        let json = encoder::json_object(variables.iter().map(|(key, value)| (*key, *value)));
+        self.add_pair("ContentVariables", &json)
+    }
+
     #[inline]
     ///Sets `SendDigits` field, to provide set of keys to dial after call is established.
     pub fn send_digits(&mut self, digits: &str) -> &mut Self {
@@ -336,11 +1024,131 @@ impl<'a> Into<TwilioRequest> for Call<'a> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Status to set on the live call.
+pub enum CallUpdateStatus {
+    ///Cancels call, which is queued or ringing.
+    Canceled,
+    ///Hangs up call, which is in progress.
+    Completed,
+}
+
+impl CallUpdateStatus {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            CallUpdateStatus::Canceled => "canceled",
+            CallUpdateStatus::Completed => "completed",
+        }
+    }
+}
+
+#[derive(Debug)]
+///Describes modification of live call, suitable for urlencoded serialization
+///
+///Request is to be posted onto `<call base>/<sid>.json`, see `CallUpdate::url`
+pub struct CallUpdate<'a> {
+    ///ID of call
+    pub sid: &'a str,
+    ///New call content, which replaces currently executed instructions
+    pub instruction: Option<CallInstruction<'a>>,
+    ///New status of call
+    pub status: Option<CallUpdateStatus>,
+    ///URL where to post status information
+    pub status_callback: Option<&'a str>,
+    ///Maximum duration of call in seconds
+    pub time_limit: Option<u32>,
+}
+
+impl<'a> CallUpdate<'a> {
+    #[inline]
+    ///Creates update of call `sid` without any modification.
+    pub const fn new(sid: &'a str) -> Self {
+        Self {
+            sid,
+            instruction: None,
+            status: None,
+            status_callback: None,
+            time_limit: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post update onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourceUrl::new().and(host.get_call_base(account_sid).as_str()).and("/").and(self.sid).and(".json")
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        match self.instruction {
+            Some(CallInstruction::Twiml(twiml)) => res.twiml(twiml),
+            Some(CallInstruction::Url(url)) => res.url(url),
+            None => &mut res,
+        };
+        if let Some(status) = self.status {
+            res.call_status(status);
+        }
+        if let Some(url) = self.status_callback {
+            res.status_url(url);
+        }
+        if let Some(time_limit) = self.time_limit {
+            res.time_limit(time_limit);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for CallUpdate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Sender of message.
+pub enum Sender<'a> {
+    ///Phone number, short code, alphanumeric sender ID or channel address (e.g. `whatsapp:+14155552345`), sent as `From`
+    Number(&'a str),
+    ///ID of messaging service, which selects sender from its pool, sent as `MessagingServiceSid`
+    MessagingService(&'a str),
+}
+
+impl<'a> Sender<'a> {
+    #[inline]
+    ///Returns name of form field.
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Sender::Number(_) => "From",
+            Sender::MessagingService(_) => "MessagingServiceSid",
+        }
+    }
+
+    #[inline]
+    ///Returns sender's identifier.
+    pub const fn as_str(&self) -> &'a str {
+        match self {
+            Sender::Number(number) => number,
+            Sender::MessagingService(sid) => sid,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Sender<'a> {
+    #[inline(always)]
+    fn from(number: &'a str) -> Self {
+        Sender::Number(number)
+    }
+}
+
 #[derive(Debug)]
 ///Describes SMS, suitable for urlencoded serialization
 pub struct Sms<'a> {
-    ///Phone number of source
-    pub from: &'a str,
+    ///Source of message
+    pub from: Sender<'a>,
     ///Phone number of destination
     pub to: &'a str,
     ///Text body
@@ -352,14 +1160,14 @@ impl<'a> Sms<'a> {
     ///Converts to generic TwilioRequest
     pub fn request(&self) -> TwilioRequest {
         let mut res = TwilioRequest::new();
-        res.from(self.from).to(self.to).body(self.body);
+        res.sender(self.from).to(self.to).body(self.body);
         res
     }
 }
 
 impl<'a> fmt::Display for Sms<'a> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        encoder::format_pair("From", self.from, fmt)?;
+        encoder::format_pair(self.from.field(), self.from.as_str(), fmt)?;
         fmt.write_str(encoder::SEP)?;
         encoder::format_pair("To", self.to, fmt)?;
         fmt.write_str(encoder::SEP)?;
@@ -415,10 +1223,93 @@ impl<'a> Into<TwilioRequest> for Mms<'a> {
     }
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error indicating that only scheduled message can be canceled.
+pub struct MessageNotScheduled;
+
+impl fmt::Display for MessageNotScheduled {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("Only scheduled message can be canceled")
+    }
+}
+
+impl std::error::Error for MessageNotScheduled {
+}
+
+#[derive(Debug)]
+///Describes modification of existing message.
+///
+///Request is to be posted onto `<sms base>/<sid>.json`, see `MessageUpdate::url`
+pub enum MessageUpdate<'a> {
+    ///Cancels scheduled message with specified ID.
+    Cancel(&'a str),
+    ///Redacts body of message with specified ID.
+    Redact(&'a str),
+}
+
+impl<'a> MessageUpdate<'a> {
+    ///Creates cancel request of message `sid`, if its known `status` allows it.
+    ///
+    ///If `status` is unknown, then request is always created.
+    pub fn cancel(sid: &'a str, status: Option<&SmsStatus>) -> Result<Self, MessageNotScheduled> {
+        match status {
+            None | Some(SmsStatus::Scheduled) => Ok(MessageUpdate::Cancel(sid)),
+            Some(_) => Err(MessageNotScheduled),
+        }
+    }
+
+    #[inline]
+    ///Creates redact request of message `sid`.
+    pub const fn redact(sid: &'a str) -> Self {
+        MessageUpdate::Redact(sid)
+    }
+
+    #[inline]
+    ///Returns ID of message.
+    pub const fn sid(&self) -> &'a str {
+        match self {
+            MessageUpdate::Cancel(sid) => sid,
+            MessageUpdate::Redact(sid) => sid,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post update onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourceUrl::new().and(host.get_sms_base(account_sid).as_str()).and("/").and(self.sid()).and(".json")
+    }
+
+    #[inline]
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        match self {
+            MessageUpdate::Cancel(_) => res.add_pair("Status", "canceled"),
+            MessageUpdate::Redact(_) => res.body(""),
+        };
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for MessageUpdate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 ///Status of message.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
 pub enum SmsStatus {
+    ///Accepted by messaging service, before sender is selected.
+    Accepted,
+    ///Scheduled for sending at later time.
+    Scheduled,
+    ///Scheduled message is canceled.
+    Canceled,
     ///In queue for sending.
     Queued,
     ///Sending is in progress.
@@ -435,14 +1326,39 @@ pub enum SmsStatus {
     Receiving,
     ///Received.
     Received,
+    ///Read by recipient (WhatsApp and other supported channels).
+    Read,
+    ///Only part of message's segments are delivered.
+    PartiallyDelivered,
+    ///Status, unknown to this library.
+    Unknown(String),
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
+impl_str_enum!(SmsStatus {
+    Accepted => "accepted",
+    Scheduled => "scheduled",
+    Canceled => "canceled",
+    Queued => "queued",
+    Sending => "sending",
+    Sent => "sent",
+    Failed => "failed",
+    Delivered => "delivered",
+    Undelivered => "undelivered",
+    Receiving => "receiving",
+    Received => "received",
+    Read => "read",
+    PartiallyDelivered => "partially_delivered",
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 ///Status of call.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
 pub enum CallStatus {
     ///In queue for sending.
     Queued,
+    ///The call is being dialed.
+    Initiated,
     ///The call is ringing.
     Ringing,
     ///The call is ongoing.
@@ -457,11 +1373,50 @@ pub enum CallStatus {
     NoAnswer,
     ///Cannot perform call.
     Failed,
+    ///Status, unknown to this library.
+    Unknown(String),
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-///Status of call.
+impl_str_enum!(CallStatus {
+    Queued => "queued",
+    Initiated => "initiated",
+    Ringing => "ringing",
+    InProgress => "in-progress",
+    Canceled => "canceled",
+    Completed => "completed",
+    Busy => "busy",
+    NoAnswer => "no-answer",
+    Failed => "failed",
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Direction of message.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum MessageDirection {
+    ///Inbound
+    Inbound,
+    ///Outbound API
+    OutboundApi,
+    ///Outbound Call, sent during call
+    OutboundCall,
+    ///Outbound Reply, sent in response to incoming message
+    OutboundReply,
+    ///Direction, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(MessageDirection {
+    Inbound => "inbound",
+    OutboundApi => "outbound-api",
+    OutboundCall => "outbound-call",
+    OutboundReply => "outbound-reply",
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Direction of call.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
 pub enum CallDirection {
     ///Inbound
     Inbound,
@@ -473,16 +1428,49 @@ pub enum CallDirection {
     TrunkingTerminating,
     ///Trunking Originating
     TrunkingOriginating,
+    ///Direction, unknown to this library.
+    Unknown(String),
 }
 
+impl_str_enum!(CallDirection {
+    Inbound => "inbound",
+    OutboundApi => "outbound-api",
+    OutboundDial => "outbound-dial",
+    TrunkingTerminating => "trunking-terminating",
+    TrunkingOriginating => "trunking-originating",
+});
+
+#[cfg(not(feature = "string-dates"))]
+///Timestamp type of result's fields.
+///
+///When `string-dates` feature is enabled, it is `String` as provided by Twilio.
+pub type ResultDateTime = TwilioDateTime;
+#[cfg(feature = "string-dates")]
+///Timestamp type of result's fields.
+///
+///When `string-dates` feature is enabled, it is `String` as provided by Twilio.
+pub type ResultDateTime = String;
+
 #[derive(Debug, Deserialize)]
 ///Result of correct SMS request.
+///
+///Describes Message resource, tolerating absent fields.
 pub struct SmsResult {
+    ///ID of account, which created message.
+    pub account_sid: Option<String>,
+    ///API version used to process message.
+    pub api_version: Option<String>,
     ///Originator of message.
-    pub from: String,
+    ///
+    ///Can be None, when message is sent via messaging service and sender is not selected yet.
+    pub from: Option<String>,
+    #[serde(default)]
     ///Destination of message.
     pub to: String,
+    #[serde(default)]
     ///Message content.
+    ///
+    ///Empty if message is redacted.
     pub body: String,
     ///ID of message
     ///
@@ -491,71 +1479,193 @@ pub struct SmsResult {
     pub sid: String,
     ///Status of message.
     pub status: SmsStatus,
-    ///URL of optional media attachment
-    pub media_url: Option<String>,
-    ///Cost of message
-    pub price: Option<String>,
-    ///Currency unit of `cost`.
-    pub price_unit: String,
+    ///Direction of message.
+    pub direction: Option<MessageDirection>,
+    ///ID of messaging service, used to send message.
+    pub messaging_service_sid: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_number_from_any")]
+    ///Number of media files attached to message.
+    pub num_media: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_number_from_any")]
+    ///Number of segments message is split into.
+    pub num_segments: Option<i64>,
+    ///Error code, if message failed or undelivered.
+    pub error_code: Option<i64>,
+    ///Error description, if message failed or undelivered.
+    pub error_message: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::price::deserialize_optional_price")]
+    ///Cost of message, if it is already known.
+    pub price: Option<Price>,
     ///Timestamp (including zone) of when message is created.
     ///
     ///Can be None, despite it obviously not making sense
-    pub date_created: Option<String>,
+    pub date_created: Option<ResultDateTime>,
     ///Timestamp (including zone) of when message is sent.
-    pub date_sent: Option<String>,
+    pub date_sent: Option<ResultDateTime>,
     ///Timestamp (including zone) of when message is updated.
-    pub date_updated: String,
+    pub date_updated: Option<ResultDateTime>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+    ///URIs of related resources (e.g. `media` and `feedback`), relative to `https://api.twilio.com`
+    pub subresource_uris: Option<HashMap<String, String>>,
 }
 
-fn deserialize_number_from_any<'de, D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
-    #[derive(Deserialize)]
-    #[serde(untagged)]
-    enum StringOrInt {
-        String(String),
-        Number(i64),
-    }
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrInt {
+    String(String),
+    Number(i64),
+}
 
-    match StringOrInt::deserialize(deserializer)? {
-        StringOrInt::String(s) => s.parse::<i64>().map_err(serde::de::Error::custom),
-        StringOrInt::Number(i) => Ok(i),
+fn deserialize_optional_number_from_any<'de, D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Option<i64>, D::Error> {
+    match Option::<StringOrInt>::deserialize(deserializer)? {
+        Some(StringOrInt::String(s)) if s.is_empty() => Ok(None),
+        Some(StringOrInt::String(s)) => s.parse::<i64>().map(Some).map_err(serde::de::Error::custom),
+        Some(StringOrInt::Number(i)) => Ok(Some(i)),
+        None => Ok(None),
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Result of answering machine detection.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum AnsweredBy {
+    ///Answered by human.
+    Human,
+    ///Answered by machine, detected at the start of greeting.
+    MachineStart,
+    ///Answered by machine, greeting ended with beep.
+    MachineEndBeep,
+    ///Answered by machine, greeting ended with silence.
+    MachineEndSilence,
+    ///Answered by machine, greeting ended in other way.
+    MachineEndOther,
+    ///Answered by fax machine.
+    Fax,
+    ///Unable to detect.
+    Undetermined,
+    ///Result, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(AnsweredBy {
+    Human => "human",
+    MachineStart => "machine_start",
+    MachineEndBeep => "machine_end_beep",
+    MachineEndSilence => "machine_end_silence",
+    MachineEndOther => "machine_end_other",
+    Fax => "fax",
+    Undetermined => "unknown",
+});
+
 #[derive(Debug, Deserialize)]
-///Result of correct SMS request.
+///Result of correct Call request.
+///
+///Describes Call resource, tolerating absent fields.
 pub struct CallResult {
-    ///Originator of message.
+    ///ID of account, which created call.
+    pub account_sid: Option<String>,
+    ///API version used to create call.
+    pub api_version: Option<String>,
+    ///ID of call, which created this call (e.g. via `<Dial>`).
+    pub parent_call_sid: Option<String>,
+    ///ID of phone number, which received or made call.
+    pub phone_number_sid: Option<String>,
+    #[serde(default)]
+    ///Originator of call.
     pub from: String,
-    ///Destination of message.
+    ///Formatted originator of call.
+    pub from_formatted: Option<String>,
+    #[serde(default)]
+    ///Destination of call.
     pub to: String,
+    ///Formatted destination of call.
+    pub to_formatted: Option<String>,
+    ///Number, which forwarded call, if provided by carrier.
+    pub forwarded_from: Option<String>,
     ///ID of call
     ///
     ///Can be used to query Call information via following link:
     ///`/2010-04-01/Accounts/{account_sid}/Calls/{sid}.json`
     pub sid: String,
-    ///Status of message.
+    ///Status of call.
     pub status: CallStatus,
     ///Caller's name
     pub caller_name: Option<String>,
-    ///Call's duration.
+    ///Result of answering machine detection, if it is enabled.
+    pub answered_by: Option<AnsweredBy>,
+    #[serde(default, deserialize_with = "deserialize_optional_number_from_any")]
+    ///Call's duration in seconds.
     pub duration: Option<i64>,
-    ///Cost of call
-    pub price: Option<String>,
-    ///Currency unit of `cost`.
-    pub price_unit: String,
+    #[serde(flatten, deserialize_with = "crate::price::deserialize_optional_price")]
+    ///Cost of call, if it is already known.
+    pub price: Option<Price>,
     ///Timestamp (including zone) of when call is created.
     ///
     ///Can be None, despite it obviously not making sense
-    pub date_created: Option<String>,
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp (including zone) of when call is updated.
+    pub date_updated: Option<ResultDateTime>,
     ///Timestamp (including zone) of when call is established.
-    pub start_time: Option<String>,
+    pub start_time: Option<ResultDateTime>,
     ///Timestamp (including zone) of when call is finished.
-    pub end_time: Option<String>,
+    pub end_time: Option<ResultDateTime>,
     ///Call's direction.
     pub direction: Option<CallDirection>,
-    #[serde(deserialize_with = "deserialize_number_from_any")]
+    #[serde(default, deserialize_with = "deserialize_optional_number_from_any")]
     ///The wait time in milliseconds before call is started.
-    pub queue_time: i64
+    pub queue_time: Option<i64>,
+    ///ID of group, call is associated with.
+    pub group_sid: Option<String>,
+    ///ID of trunk, call is made through.
+    pub trunk_sid: Option<String>,
+    ///Annotation of call.
+    pub annotation: Option<String>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+    ///URIs of related resources (e.g. `recordings` and `notifications`), relative to `https://api.twilio.com`
+    pub subresource_uris: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize)]
+///Paging information of list resources.
+pub struct PageMeta {
+    ///Index of page, starting from 0.
+    pub page: Option<u32>,
+    ///Maximum number of resources per page.
+    pub page_size: Option<u32>,
+    ///Index of first resource on the page.
+    pub start: Option<u32>,
+    ///Index of last resource on the page.
+    pub end: Option<u32>,
+    ///URI of current page.
+    pub uri: Option<String>,
+    ///URI of first page.
+    pub first_page_uri: Option<String>,
+    ///URI of next page, if any.
+    pub next_page_uri: Option<String>,
+    ///URI of previous page, if any.
+    pub previous_page_uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+///Paging information of product API lists, found under `meta` key.
+pub struct ListMeta {
+    ///Index of page, starting from 0.
+    pub page: Option<u32>,
+    ///Maximum number of resources per page.
+    pub page_size: Option<u32>,
+    ///Key of resources in the list.
+    pub key: Option<String>,
+    ///URL of current page.
+    pub url: Option<String>,
+    ///URL of first page.
+    pub first_page_url: Option<String>,
+    ///URL of next page, if any.
+    pub next_page_url: Option<String>,
+    ///URL of previous page, if any.
+    pub previous_page_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
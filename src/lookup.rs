@@ -0,0 +1,389 @@
+//! Lookup v2 API `lookups.twilio.com/v2`
+//!
+//! Basic lookup validates and formats phone number for free.
+//! Additional information is requested via data packages, selected by `LookupField`.
+
+use core::convert::TryFrom;
+
+use serde::Deserialize;
+
+use super::{encoder, Host, ProductPath, Product, ResourceUrl, TwilioRequest, PhoneNumber, InvalidPhoneNumber};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Data package to request in addition to basic lookup.
+pub enum LookupField {
+    ///Line type and carrier.
+    LineTypeIntelligence,
+    ///Caller name (US only).
+    CallerName,
+    ///Last SIM change.
+    SimSwap,
+    ///Unconditional call forwarding status.
+    CallForwarding,
+    ///Reachability of number.
+    LineStatus,
+    ///Match of number against provided identity, see `IdentityQuery`.
+    IdentityMatch,
+    ///Risk of SMS pumping fraud.
+    SmsPumpingRisk,
+}
+
+impl LookupField {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            LookupField::LineTypeIntelligence => "line_type_intelligence",
+            LookupField::CallerName => "caller_name",
+            LookupField::SimSwap => "sim_swap",
+            LookupField::CallForwarding => "call_forwarding",
+            LookupField::LineStatus => "line_status",
+            LookupField::IdentityMatch => "identity_match",
+            LookupField::SmsPumpingRisk => "sms_pumping_risk",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+///Identity to match phone number against, required by `LookupField::IdentityMatch`.
+pub struct IdentityQuery<'a> {
+    ///First name
+    pub first_name: Option<&'a str>,
+    ///Last name
+    pub last_name: Option<&'a str>,
+    ///First line of address
+    pub address_line1: Option<&'a str>,
+    ///Second line of address
+    pub address_line2: Option<&'a str>,
+    ///City
+    pub city: Option<&'a str>,
+    ///State or province
+    pub state: Option<&'a str>,
+    ///Postal code
+    pub postal_code: Option<&'a str>,
+    ///ISO country code of address
+    pub address_country_code: Option<&'a str>,
+    ///National identifier (e.g. SSN)
+    pub national_id: Option<&'a str>,
+    ///Date of birth in `YYYYMMDD` format
+    pub date_of_birth: Option<&'a str>,
+}
+
+impl<'a> IdentityQuery<'a> {
+    fn write(&self, res: &mut TwilioRequest) {
+        let fields = [
+            ("FirstName", self.first_name),
+            ("LastName", self.last_name),
+            ("AddressLine1", self.address_line1),
+            ("AddressLine2", self.address_line2),
+            ("City", self.city),
+            ("State", self.state),
+            ("PostalCode", self.postal_code),
+            ("AddressCountryCode", self.address_country_code),
+            ("NationalId", self.national_id),
+            ("DateOfBirth", self.date_of_birth),
+        ];
+        for (field, value) in fields.iter() {
+            if let Some(value) = value {
+                res.add_pair(field, value);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+///Describes lookup of phone number, suitable for urlencoded serialization
+///
+///Request is to be sent as query string via `GET` onto `LookupRequest::url`
+pub struct LookupRequest<'a> {
+    ///Phone number in E.164 or national format
+    pub phone_number: &'a str,
+    ///Data packages to request
+    pub fields: &'a [LookupField],
+    ///ISO country code, required when `phone_number` is in national format
+    pub country_code: Option<&'a str>,
+    ///Identity to match, when requesting `LookupField::IdentityMatch`
+    pub identity: Option<IdentityQuery<'a>>,
+}
+
+impl<'a> LookupRequest<'a> {
+    #[inline]
+    ///Creates basic lookup of `phone_number` without data packages.
+    pub const fn new(phone_number: &'a str) -> Self {
+        Self {
+            phone_number,
+            fields: &[],
+            country_code: None,
+            identity: None,
+        }
+    }
+
+    ///Creates URL to perform lookup onto.
+    ///
+    ///Phone number is percent-encoded, so national format (e.g. `(415) 992-9960`) can be used as it is.
+    ///Fails if phone number is empty or too long to fit into URL.
+    pub fn url(&self, host: &Host<'_>) -> Result<ResourceUrl, InvalidPhoneNumber> {
+        let mut segment = String::with_capacity(self.phone_number.len() + 1);
+        segment.push('/');
+        encoder::push_path_segment(self.phone_number, &mut segment);
+
+        let mut url = ProductPath::with_host(host, Product::Lookups).segment("PhoneNumbers").into_url();
+        if self.phone_number.is_empty() || segment.len() > url.remaining() {
+            return Err(InvalidPhoneNumber);
+        }
+        url.push_str(&segment);
+        Ok(url)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        if !self.fields.is_empty() {
+            res.lookup_fields(self.fields);
+        }
+        if let Some(country_code) = self.country_code {
+            res.country_code(country_code);
+        }
+        if let Some(identity) = &self.identity {
+            identity.write(&mut res);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for LookupRequest<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Type of phone line.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum LineType {
+    ///Landline.
+    Landline,
+    ///Mobile.
+    Mobile,
+    ///VoIP, associated with physical address.
+    FixedVoip,
+    ///VoIP, not associated with physical address.
+    NonFixedVoip,
+    ///Personal number, forwarded elsewhere.
+    Personal,
+    ///Toll free.
+    TollFree,
+    ///Premium rate.
+    Premium,
+    ///Shared cost.
+    SharedCost,
+    ///Universal access number.
+    Uan,
+    ///Voicemail only.
+    Voicemail,
+    ///Pager.
+    Pager,
+    ///Type, unknown to this library or Twilio.
+    Unknown(String),
+}
+
+impl_str_enum!(LineType {
+    Landline => "landline",
+    Mobile => "mobile",
+    FixedVoip => "fixedVoip",
+    NonFixedVoip => "nonFixedVoip",
+    Personal => "personal",
+    TollFree => "tollFree",
+    Premium => "premium",
+    SharedCost => "sharedCost",
+    Uan => "uan",
+    Voicemail => "voicemail",
+    Pager => "pager",
+});
+
+#[derive(Debug, Deserialize)]
+///`line_type_intelligence` data package.
+pub struct LineTypeIntelligence {
+    #[serde(rename = "type")]
+    ///Type of line.
+    pub line_type: Option<LineType>,
+    ///Name of carrier.
+    pub carrier_name: Option<String>,
+    ///Mobile country code.
+    pub mobile_country_code: Option<String>,
+    ///Mobile network code.
+    pub mobile_network_code: Option<String>,
+    ///Error code, if information is unavailable.
+    pub error_code: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+///`caller_name` data package.
+pub struct CallerName {
+    ///Name of owner.
+    pub caller_name: Option<String>,
+    ///Type of owner (`BUSINESS` or `CONSUMER`).
+    pub caller_type: Option<String>,
+    ///Error code, if information is unavailable.
+    pub error_code: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+///Last SIM change.
+pub struct LastSimSwap {
+    ///Timestamp of last SIM change, if known.
+    pub last_sim_swap_date: Option<String>,
+    ///Period, which `swapped_in_period` refers to (e.g. `PT24H`).
+    pub swapped_period: Option<String>,
+    ///Whether SIM changed within `swapped_period`.
+    pub swapped_in_period: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+///`sim_swap` data package.
+pub struct SimSwap {
+    ///Last SIM change.
+    pub last_sim_swap: Option<LastSimSwap>,
+    ///Name of carrier.
+    pub carrier_name: Option<String>,
+    ///Mobile country code.
+    pub mobile_country_code: Option<String>,
+    ///Mobile network code.
+    pub mobile_network_code: Option<String>,
+    ///Error code, if information is unavailable.
+    pub error_code: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+///`call_forwarding` data package.
+pub struct CallForwarding {
+    ///Whether unconditional call forwarding is enabled.
+    pub call_forwarding_status: Option<bool>,
+    ///Error code, if information is unavailable.
+    pub error_code: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+///`line_status` data package.
+pub struct LineStatus {
+    ///Status of line (`active`, `reachable`, `unreachable`, `inactive` or `unknown`).
+    pub status: Option<String>,
+    ///Error code, if information is unavailable.
+    pub error_code: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+///`identity_match` data package.
+///
+///Each match is one of `exact_match`, `high_partial_match`, `partial_match`, `no_match` or `no_data_available`.
+pub struct IdentityMatch {
+    ///Match of first name.
+    pub first_name_match: Option<String>,
+    ///Match of last name.
+    pub last_name_match: Option<String>,
+    ///Match of address lines.
+    pub address_lines_match: Option<String>,
+    ///Match of city.
+    pub city_match: Option<String>,
+    ///Match of state.
+    pub state_match: Option<String>,
+    ///Match of postal code.
+    pub postal_code_match: Option<String>,
+    ///Match of country.
+    pub address_country_match: Option<String>,
+    ///Match of national identifier.
+    pub national_id_match: Option<String>,
+    ///Match of date of birth.
+    pub date_of_birth_match: Option<String>,
+    ///Overall score from 0 to 100.
+    pub summary_score: Option<u8>,
+    ///Error code, if information is unavailable.
+    pub error_code: Option<i64>,
+    ///Error message, if information is unavailable.
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+///`sms_pumping_risk` data package.
+pub struct SmsPumpingRisk {
+    ///Risk category of carrier (`low`, `mild`, `moderate` or `high`).
+    pub carrier_risk_category: Option<String>,
+    ///Whether number is blocked by SMS pumping protection.
+    pub number_blocked: Option<bool>,
+    ///Timestamp of when number is blocked.
+    pub number_blocked_date: Option<String>,
+    ///Number of times number is blocked during last 3 months.
+    pub number_blocked_last_3_months: Option<u32>,
+    ///Risk score from 0 to 100.
+    pub sms_pumping_risk_score: Option<u8>,
+    ///Error code, if information is unavailable.
+    pub error_code: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+///Result of phone number lookup.
+///
+///Data packages are present only when requested and available.
+pub struct LookupResult {
+    ///Phone number in E.164 format, when valid.
+    pub phone_number: Option<String>,
+    ///Phone number in national format.
+    pub national_format: Option<String>,
+    ///ISO country code.
+    pub country_code: Option<String>,
+    ///Calling code of country.
+    pub calling_country_code: Option<String>,
+    #[serde(default)]
+    ///Whether phone number is valid.
+    pub valid: bool,
+    #[serde(default)]
+    ///Reasons of why phone number is invalid (e.g. `TOO_SHORT`).
+    pub validation_errors: Vec<String>,
+    ///`line_type_intelligence` data package.
+    pub line_type_intelligence: Option<LineTypeIntelligence>,
+    ///`caller_name` data package.
+    pub caller_name: Option<CallerName>,
+    ///`sim_swap` data package.
+    pub sim_swap: Option<SimSwap>,
+    ///`call_forwarding` data package.
+    pub call_forwarding: Option<CallForwarding>,
+    ///`line_status` data package.
+    pub line_status: Option<LineStatus>,
+    ///`identity_match` data package.
+    pub identity_match: Option<IdentityMatch>,
+    ///`sms_pumping_risk` data package.
+    pub sms_pumping_risk: Option<SmsPumpingRisk>,
+    ///URL of resource.
+    pub url: Option<String>,
+}
+
+impl LookupResult {
+    #[inline]
+    ///Returns phone number, if it is valid.
+    pub fn to_phone_number(&self) -> Result<PhoneNumber, InvalidPhoneNumber> {
+        match (self.valid, self.phone_number.as_deref()) {
+            (true, Some(number)) => PhoneNumber::parse(number),
+            _ => Err(InvalidPhoneNumber),
+        }
+    }
+}
+
+impl TryFrom<LookupResult> for PhoneNumber {
+    type Error = InvalidPhoneNumber;
+
+    #[inline(always)]
+    fn try_from(result: LookupResult) -> Result<Self, Self::Error> {
+        result.to_phone_number()
+    }
+}
+
+impl TryFrom<&LookupResult> for PhoneNumber {
+    type Error = InvalidPhoneNumber;
+
+    #[inline(always)]
+    fn try_from(result: &LookupResult) -> Result<Self, Self::Error> {
+        result.to_phone_number()
+    }
+}
@@ -0,0 +1,440 @@
+//! Messaging v1 API `messaging.twilio.com/v1`
+//!
+//! Messaging service owns pool of senders (phone numbers, short codes and alphanumeric sender IDs).
+//! Messages sent with `Sender::MessagingService` use sender, selected by service from its pool.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{Host, ProductPath, Product, ResourceUrl, ResultDateTime, ListMeta, TwilioRequest, TwilioMethod};
+
+#[derive(Debug, Deserialize)]
+///Messaging service.
+pub struct Service {
+    ///ID of service
+    pub sid: String,
+    ///ID of account, which owns service.
+    pub account_sid: Option<String>,
+    ///Name of service.
+    pub friendly_name: Option<String>,
+    ///URL to invoke on incoming message.
+    pub inbound_request_url: Option<String>,
+    ///HTTP method to access `inbound_request_url`.
+    pub inbound_method: Option<TwilioMethod>,
+    ///URL to invoke when `inbound_request_url` fails.
+    pub fallback_url: Option<String>,
+    ///HTTP method to access `fallback_url`.
+    pub fallback_method: Option<TwilioMethod>,
+    ///URL to send status of outgoing messages onto.
+    pub status_callback: Option<String>,
+    #[serde(default)]
+    ///Whether the same sender is used for each recipient.
+    pub sticky_sender: bool,
+    #[serde(default)]
+    ///Whether Unicode characters are replaced with GSM-7 look-alikes.
+    pub smart_encoding: bool,
+    #[serde(default)]
+    ///Whether sender with the same area code as recipient is preferred.
+    pub area_code_geomatch: bool,
+    #[serde(default)]
+    ///Whether long code is used when short code fails.
+    pub fallback_to_long_code: bool,
+    #[serde(default)]
+    ///Whether MMS is converted into SMS with media URL when MMS is unsupported.
+    pub mms_converter: bool,
+    ///Number of seconds message can stay in queue.
+    pub validity_period: Option<u16>,
+    ///Intended use case of service (e.g. `marketing`).
+    pub usecase: Option<String>,
+    ///Timestamp of when service is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when service is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+    #[serde(default)]
+    ///URLs of sub-resources.
+    pub links: HashMap<String, String>,
+}
+
+impl Service {
+    #[inline]
+    ///Creates URL to list or create services.
+    pub const fn list_url(host: &Host<'_>) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Messaging).segment("Services").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch, update or delete service `sid`.
+    pub const fn url(host: &Host<'_>, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Messaging).segment("Services").sid(sid).into_url()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of messaging services.
+pub struct ServiceList {
+    ///Services
+    pub services: Vec<Service>,
+    ///Paging information.
+    pub meta: ListMeta,
+}
+
+#[derive(Debug, Default)]
+///Configuration of messaging service, shared by create and update requests.
+///
+///Fields set to `None` are left unchanged.
+pub struct ServiceConfig<'a> {
+    ///URL to invoke on incoming message
+    pub inbound_request_url: Option<&'a str>,
+    ///HTTP method to access `inbound_request_url`
+    pub inbound_method: Option<TwilioMethod>,
+    ///URL to invoke when `inbound_request_url` fails
+    pub fallback_url: Option<&'a str>,
+    ///HTTP method to access `fallback_url`
+    pub fallback_method: Option<TwilioMethod>,
+    ///URL to send status of outgoing messages onto
+    pub status_callback: Option<&'a str>,
+    ///Whether to use the same sender for each recipient
+    pub sticky_sender: Option<bool>,
+    ///Whether to replace Unicode characters with GSM-7 look-alikes
+    pub smart_encoding: Option<bool>,
+    ///Number of seconds message can stay in queue, from 1 to 14400
+    pub validity_period: Option<u16>,
+    ///Whether to prefer sender with the same area code as recipient
+    pub area_code_geomatch: Option<bool>,
+    ///Whether to use long code when short code fails
+    pub fallback_to_long_code: Option<bool>,
+    ///Whether to convert MMS into SMS with media URL when MMS is unsupported
+    pub mms_converter: Option<bool>,
+}
+
+impl<'a> ServiceConfig<'a> {
+    fn write(&self, res: &mut TwilioRequest) {
+        match (self.inbound_request_url, self.inbound_method) {
+            (Some(url), Some(method)) => res.inbound_request_url_with_method(method, url),
+            (Some(url), None) => res.inbound_request_url(url),
+            (None, Some(method)) => res.add_pair("InboundMethod", method.as_str()),
+            (None, None) => res,
+        };
+        match (self.fallback_url, self.fallback_method) {
+            (Some(url), Some(method)) => res.fallback_url_with_method(method, url),
+            (Some(url), None) => res.fallback_url(url),
+            (None, Some(method)) => res.add_pair("FallbackMethod", method.as_str()),
+            (None, None) => res,
+        };
+        if let Some(url) = self.status_callback {
+            res.status_url(url);
+        }
+        if let Some(value) = self.sticky_sender {
+            res.sticky_sender(value);
+        }
+        if let Some(value) = self.smart_encoding {
+            res.smart_encoding(value);
+        }
+        if let Some(period) = self.validity_period {
+            res.validity_period(period);
+        }
+        if let Some(value) = self.area_code_geomatch {
+            res.area_code_geomatch(value);
+        }
+        if let Some(value) = self.fallback_to_long_code {
+            res.fallback_to_long_code(value);
+        }
+        if let Some(value) = self.mms_converter {
+            res.mms_converter(value);
+        }
+    }
+}
+
+#[derive(Debug)]
+///Describes new messaging service, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Service::list_url`
+pub struct ServiceCreate<'a> {
+    ///Name of service
+    pub friendly_name: &'a str,
+    ///Configuration
+    pub config: ServiceConfig<'a>,
+}
+
+impl<'a> ServiceCreate<'a> {
+    #[inline]
+    ///Creates service with default configuration.
+    pub fn new(friendly_name: &'a str) -> Self {
+        Self {
+            friendly_name,
+            config: ServiceConfig::default(),
+        }
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.friendly_name(self.friendly_name);
+        self.config.write(&mut res);
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for ServiceCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug)]
+///Describes modification of messaging service, suitable for urlencoded serialization
+///
+///Request is to be posted onto `ServiceUpdate::url`
+pub struct ServiceUpdate<'a> {
+    ///ID of service
+    pub sid: &'a str,
+    ///New name of service
+    pub friendly_name: Option<&'a str>,
+    ///Configuration
+    pub config: ServiceConfig<'a>,
+}
+
+impl<'a> ServiceUpdate<'a> {
+    #[inline]
+    ///Creates update, which leaves service unchanged.
+    pub fn new(sid: &'a str) -> Self {
+        Self {
+            sid,
+            friendly_name: None,
+            config: ServiceConfig::default(),
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post update onto.
+    pub const fn url(&self, host: &Host<'_>) -> ResourceUrl {
+        Service::url(host, self.sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        if let Some(name) = self.friendly_name {
+            res.friendly_name(name);
+        }
+        self.config.write(&mut res);
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for ServiceUpdate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Phone number in sender pool of messaging service.
+pub struct ServicePhoneNumber {
+    ///ID of phone number
+    pub sid: String,
+    ///ID of account, which owns phone number.
+    pub account_sid: Option<String>,
+    ///ID of service.
+    pub service_sid: Option<String>,
+    ///Phone number in E.164 format.
+    pub phone_number: Option<String>,
+    ///ISO country code.
+    pub country_code: Option<String>,
+    #[serde(default)]
+    ///Capabilities of phone number (e.g. `SMS`, `MMS`).
+    pub capabilities: Vec<String>,
+    ///Timestamp of when phone number is added.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when phone number is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+}
+
+impl ServicePhoneNumber {
+    #[inline]
+    ///Creates URL to list or add phone numbers of service `service_sid`.
+    pub const fn list_url(host: &Host<'_>, service_sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Messaging).segment("Services").sid(service_sid).segment("PhoneNumbers").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or remove phone number `sid` of service `service_sid`.
+    ///
+    ///Phone number is removed from sender pool by `DELETE` request onto this URL.
+    pub const fn url(host: &Host<'_>, service_sid: &str, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Messaging).segment("Services").sid(service_sid).segment("PhoneNumbers").sid(sid).into_url()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of phone numbers in sender pool.
+pub struct ServicePhoneNumberList {
+    ///Phone numbers
+    pub phone_numbers: Vec<ServicePhoneNumber>,
+    ///Paging information.
+    pub meta: ListMeta,
+}
+
+#[derive(Debug, Deserialize)]
+///Short code in sender pool of messaging service.
+pub struct ServiceShortCode {
+    ///ID of short code
+    pub sid: String,
+    ///ID of account, which owns short code.
+    pub account_sid: Option<String>,
+    ///ID of service.
+    pub service_sid: Option<String>,
+    ///Short code.
+    pub short_code: Option<String>,
+    ///ISO country code.
+    pub country_code: Option<String>,
+    #[serde(default)]
+    ///Capabilities of short code (e.g. `SMS`, `MMS`).
+    pub capabilities: Vec<String>,
+    ///Timestamp of when short code is added.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when short code is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+}
+
+impl ServiceShortCode {
+    #[inline]
+    ///Creates URL to list or add short codes of service `service_sid`.
+    pub const fn list_url(host: &Host<'_>, service_sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Messaging).segment("Services").sid(service_sid).segment("ShortCodes").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or remove short code `sid` of service `service_sid`.
+    ///
+    ///Short code is removed from sender pool by `DELETE` request onto this URL.
+    pub const fn url(host: &Host<'_>, service_sid: &str, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Messaging).segment("Services").sid(service_sid).segment("ShortCodes").sid(sid).into_url()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of short codes in sender pool.
+pub struct ServiceShortCodeList {
+    ///Short codes
+    pub short_codes: Vec<ServiceShortCode>,
+    ///Paging information.
+    pub meta: ListMeta,
+}
+
+#[derive(Debug, Deserialize)]
+///Alphanumeric sender ID in sender pool of messaging service.
+pub struct AlphaSender {
+    ///ID of alphanumeric sender
+    pub sid: String,
+    ///ID of account, which owns alphanumeric sender.
+    pub account_sid: Option<String>,
+    ///ID of service.
+    pub service_sid: Option<String>,
+    ///Alphanumeric sender ID.
+    pub alpha_sender: Option<String>,
+    #[serde(default)]
+    ///Capabilities of alphanumeric sender (e.g. `SMS`).
+    pub capabilities: Vec<String>,
+    ///Timestamp of when alphanumeric sender is added.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when alphanumeric sender is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+}
+
+impl AlphaSender {
+    #[inline]
+    ///Creates URL to list or add alphanumeric senders of service `service_sid`.
+    pub const fn list_url(host: &Host<'_>, service_sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Messaging).segment("Services").sid(service_sid).segment("AlphaSenders").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or remove alphanumeric sender `sid` of service `service_sid`.
+    ///
+    ///Alphanumeric sender is removed from sender pool by `DELETE` request onto this URL.
+    pub const fn url(host: &Host<'_>, service_sid: &str, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Messaging).segment("Services").sid(service_sid).segment("AlphaSenders").sid(sid).into_url()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of alphanumeric senders in sender pool.
+pub struct AlphaSenderList {
+    ///Alphanumeric senders
+    pub alpha_senders: Vec<AlphaSender>,
+    ///Paging information.
+    pub meta: ListMeta,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Sender to add into pool of messaging service.
+pub enum PoolSender<'a> {
+    ///ID of incoming phone number (`PN...`)
+    PhoneNumber(&'a str),
+    ///ID of short code (`SC...`)
+    ShortCode(&'a str),
+    ///Alphanumeric sender ID, up to 11 characters
+    AlphaSender(&'a str),
+}
+
+#[derive(Debug)]
+///Describes addition of sender into pool of messaging service, suitable for urlencoded serialization
+///
+///Request is to be posted onto `SenderAdd::url`
+pub struct SenderAdd<'a> {
+    ///ID of service
+    pub service_sid: &'a str,
+    ///Sender to add
+    pub sender: PoolSender<'a>,
+}
+
+impl<'a> SenderAdd<'a> {
+    #[inline]
+    ///Creates addition of `sender` into pool of service `service_sid`.
+    pub const fn new(service_sid: &'a str, sender: PoolSender<'a>) -> Self {
+        Self {
+            service_sid,
+            sender,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post addition onto, depending on kind of sender.
+    pub const fn url(&self, host: &Host<'_>) -> ResourceUrl {
+        match self.sender {
+            PoolSender::PhoneNumber(_) => ServicePhoneNumber::list_url(host, self.service_sid),
+            PoolSender::ShortCode(_) => ServiceShortCode::list_url(host, self.service_sid),
+            PoolSender::AlphaSender(_) => AlphaSender::list_url(host, self.service_sid),
+        }
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        match self.sender {
+            PoolSender::PhoneNumber(sid) => res.phone_number_sid(sid),
+            PoolSender::ShortCode(sid) => res.short_code_sid(sid),
+            PoolSender::AlphaSender(sender) => res.alpha_sender(sender),
+        };
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for SenderAdd<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
@@ -0,0 +1,349 @@
+//! Builders of REST API resource URLs.
+
+use super::Host;
+use super::host::REST_API_PATH;
+
+///Storage of resource URL, enough to fit any nested resource.
+pub type ResourceUrl = str_buf::StrBuf<255>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Resource of v2010 REST API, scoped by account.
+pub enum Resource {
+    ///`Accounts`, for subaccounts.
+    Accounts,
+    ///`Addresses`
+    Addresses,
+    ///`Applications`
+    Applications,
+    ///`AvailablePhoneNumbers`
+    AvailablePhoneNumbers,
+    ///`Balance`
+    Balance,
+    ///`Calls`
+    Calls,
+    ///`Conferences`
+    Conferences,
+    ///`Events` of call.
+    Events,
+    ///`Feedback` of message.
+    Feedback,
+    ///`IncomingPhoneNumbers`
+    IncomingPhoneNumbers,
+    ///`Keys`
+    Keys,
+    ///`Local` numbers.
+    Local,
+    ///`Media` of message.
+    Media,
+    ///`Members` of queue.
+    Members,
+    ///`Messages`
+    Messages,
+    ///`Mobile` numbers.
+    Mobile,
+    ///`Notifications`
+    Notifications,
+    ///`OutgoingCallerIds`
+    OutgoingCallerIds,
+    ///`Participants` of conference.
+    Participants,
+    ///`Payments` of call.
+    Payments,
+    ///`Queues`
+    Queues,
+    ///`Records` of usage.
+    Records,
+    ///`Recordings`
+    Recordings,
+    ///`ShortCodes`
+    ShortCodes,
+    ///`Siprec` of call.
+    Siprec,
+    ///`Streams` of call.
+    Streams,
+    ///`TollFree` numbers.
+    TollFree,
+    ///`Transcriptions`
+    Transcriptions,
+    ///`Triggers` of usage.
+    Triggers,
+    ///`Usage`
+    Usage,
+    ///`Daily` usage records.
+    Daily,
+    ///`Monthly` usage records.
+    Monthly,
+    ///`Yearly` usage records.
+    Yearly,
+    ///`Today` usage records.
+    Today,
+    ///`Yesterday` usage records.
+    Yesterday,
+    ///`ThisMonth` usage records.
+    ThisMonth,
+    ///`LastMonth` usage records.
+    LastMonth,
+    ///`AllTime` usage records.
+    AllTime,
+}
+
+impl Resource {
+    ///Returns path segment of resource.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Resource::Accounts => "Accounts",
+            Resource::Addresses => "Addresses",
+            Resource::Applications => "Applications",
+            Resource::AvailablePhoneNumbers => "AvailablePhoneNumbers",
+            Resource::Balance => "Balance",
+            Resource::Calls => "Calls",
+            Resource::Conferences => "Conferences",
+            Resource::Events => "Events",
+            Resource::Feedback => "Feedback",
+            Resource::IncomingPhoneNumbers => "IncomingPhoneNumbers",
+            Resource::Keys => "Keys",
+            Resource::Local => "Local",
+            Resource::Media => "Media",
+            Resource::Members => "Members",
+            Resource::Messages => "Messages",
+            Resource::Mobile => "Mobile",
+            Resource::Notifications => "Notifications",
+            Resource::OutgoingCallerIds => "OutgoingCallerIds",
+            Resource::Participants => "Participants",
+            Resource::Payments => "Payments",
+            Resource::Queues => "Queues",
+            Resource::Records => "Records",
+            Resource::Recordings => "Recordings",
+            Resource::ShortCodes => "ShortCodes",
+            Resource::Siprec => "Siprec",
+            Resource::Streams => "Streams",
+            Resource::TollFree => "TollFree",
+            Resource::Transcriptions => "Transcriptions",
+            Resource::Triggers => "Triggers",
+            Resource::Usage => "Usage",
+            Resource::Daily => "Daily",
+            Resource::Monthly => "Monthly",
+            Resource::Yearly => "Yearly",
+            Resource::Today => "Today",
+            Resource::Yesterday => "Yesterday",
+            Resource::ThisMonth => "ThisMonth",
+            Resource::LastMonth => "LastMonth",
+            Resource::AllTime => "AllTime",
+        }
+    }
+}
+
+const fn push(buffer: ResourceUrl, text: &str) -> ResourceUrl {
+    assert!(buffer.remaining() >= text.len(), "ResourcePath overflow");
+    buffer.and(text)
+}
+
+#[derive(Debug, Clone, Copy)]
+///Builder of URL for resources of the account.
+///
+///Account can be either main account or its subaccount, as long as credentials have access to it.
+///
+///```
+///use twilio_data::{ResourcePath, Resource, ResourceUrl};
+///
+///const URL: ResourceUrl = ResourcePath::new("ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX").resource(Resource::Messages)
+///                                                                                .sid("SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")
+///                                                                                .resource(Resource::Media)
+///                                                                                .json();
+///assert_eq!(URL, "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages/SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Media.json");
+///```
+///
+///Overflow of `ResourceUrl` results in panic, which is compile time error in `const` context.
+pub struct ResourcePath {
+    buffer: ResourceUrl,
+}
+
+impl ResourcePath {
+    #[inline]
+    ///Starts path for resources of `account_sid` using global host.
+    pub const fn new(account_sid: &str) -> Self {
+        Self::with_host(&Host::GLOBAL, account_sid)
+    }
+
+    #[inline]
+    ///Starts path for resources of `account_sid` using specified `host`.
+    pub const fn with_host(host: &Host<'_>, account_sid: &str) -> Self {
+        let buffer = host.write_base("api", ResourceUrl::new());
+        let buffer = push(buffer, REST_API_PATH);
+        let buffer = push(buffer, "/");
+        Self {
+            buffer: push(buffer, account_sid),
+        }
+    }
+
+    #[inline]
+    ///Appends `resource`.
+    pub const fn resource(self, resource: Resource) -> Self {
+        self.segment(resource.as_str())
+    }
+
+    #[inline]
+    ///Appends identifier of resource.
+    pub const fn sid(self, sid: &str) -> Self {
+        self.segment(sid)
+    }
+
+    #[inline]
+    ///Appends arbitrary path segment (e.g. country code).
+    pub const fn segment(self, segment: &str) -> Self {
+        let buffer = push(self.buffer, "/");
+        Self {
+            buffer: push(buffer, segment),
+        }
+    }
+
+    #[inline]
+    ///Returns URL with specified extension (e.g. `json`).
+    pub const fn extension(self, extension: &str) -> ResourceUrl {
+        push(push(self.buffer, "."), extension)
+    }
+
+    #[inline]
+    ///Returns URL of JSON representation, suitable for API requests.
+    pub const fn json(self) -> ResourceUrl {
+        self.extension("json")
+    }
+
+    #[inline]
+    ///Returns URL as it is, without extension.
+    pub const fn into_url(self) -> ResourceUrl {
+        self.buffer
+    }
+
+    #[inline]
+    ///Returns current path as string.
+    pub const fn as_str(&self) -> &str {
+        self.buffer.as_str()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Twilio product API, hosted separately from v2010 REST API.
+pub enum Product {
+    ///Content API `content.twilio.com/v1`
+    Content,
+    ///Conversations API `conversations.twilio.com/v1`
+    Conversations,
+    ///Lookup API `lookups.twilio.com/v2`
+    Lookups,
+    ///Messaging API `messaging.twilio.com/v1`
+    Messaging,
+    ///Notify API `notify.twilio.com/v1`
+    Notify,
+    ///Serverless API `serverless.twilio.com/v1`
+    Serverless,
+    ///Studio API `studio.twilio.com/v2`
+    Studio,
+    ///Sync API `sync.twilio.com/v1`
+    Sync,
+    ///TaskRouter API `taskrouter.twilio.com/v1`
+    TaskRouter,
+    ///Trunking API `trunking.twilio.com/v1`
+    Trunking,
+    ///Verify API `verify.twilio.com/v2`
+    Verify,
+    ///Video API `video.twilio.com/v1`
+    Video,
+    ///Voice API `voice.twilio.com/v1`
+    Voice,
+}
+
+impl Product {
+    ///Returns subdomain of product's host.
+    pub const fn subdomain(&self) -> &'static str {
+        match self {
+            Product::Content => "content",
+            Product::Conversations => "conversations",
+            Product::Lookups => "lookups",
+            Product::Messaging => "messaging",
+            Product::Notify => "notify",
+            Product::Serverless => "serverless",
+            Product::Studio => "studio",
+            Product::Sync => "sync",
+            Product::TaskRouter => "taskrouter",
+            Product::Trunking => "trunking",
+            Product::Verify => "verify",
+            Product::Video => "video",
+            Product::Voice => "voice",
+        }
+    }
+
+    ///Returns API version, used as first path segment.
+    pub const fn version(&self) -> &'static str {
+        match self {
+            Product::Lookups | Product::Studio | Product::Verify => "v2",
+            Product::Content | Product::Conversations | Product::Messaging | Product::Notify | Product::Serverless
+            | Product::Sync | Product::TaskRouter | Product::Trunking | Product::Video | Product::Voice => "v1",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+///Builder of URL for resources of product API.
+///
+///Unlike v2010 REST API, products are not scoped by account and do not use `.json` extension.
+///
+///```
+///use twilio_data::{ProductPath, Product, ResourceUrl};
+///
+///const URL: ResourceUrl = ProductPath::new(Product::Verify).segment("Services")
+///                                                          .sid("VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")
+///                                                          .segment("Verifications")
+///                                                          .into_url();
+///assert_eq!(URL, "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Verifications");
+///```
+///
+///Overflow of `ResourceUrl` results in panic, which is compile time error in `const` context.
+pub struct ProductPath {
+    buffer: ResourceUrl,
+}
+
+impl ProductPath {
+    #[inline]
+    ///Starts path for resources of `product` using global host.
+    pub const fn new(product: Product) -> Self {
+        Self::with_host(&Host::GLOBAL, product)
+    }
+
+    #[inline]
+    ///Starts path for resources of `product` using specified `host`.
+    pub const fn with_host(host: &Host<'_>, product: Product) -> Self {
+        let buffer = host.write_base(product.subdomain(), ResourceUrl::new());
+        let buffer = push(buffer, "/");
+        Self {
+            buffer: push(buffer, product.version()),
+        }
+    }
+
+    #[inline]
+    ///Appends identifier of resource.
+    pub const fn sid(self, sid: &str) -> Self {
+        self.segment(sid)
+    }
+
+    #[inline]
+    ///Appends path segment (e.g. `Services`).
+    pub const fn segment(self, segment: &str) -> Self {
+        let buffer = push(self.buffer, "/");
+        Self {
+            buffer: push(buffer, segment),
+        }
+    }
+
+    #[inline]
+    ///Returns URL.
+    pub const fn into_url(self) -> ResourceUrl {
+        self.buffer
+    }
+
+    #[inline]
+    ///Returns current path as string.
+    pub const fn as_str(&self) -> &str {
+        self.buffer.as_str()
+    }
+}
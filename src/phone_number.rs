@@ -0,0 +1,569 @@
+//! Phone number search and provisioning.
+
+use core::fmt;
+use core::str::FromStr;
+
+use serde::Deserialize;
+use serde::de::{self, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use super::{Host, ResourcePath, Resource, ResourceUrl, ResultDateTime, PageMeta, TwilioRequest, TwilioMethod};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error parsing `PhoneNumber`.
+pub struct InvalidPhoneNumber;
+
+impl fmt::Display for InvalidPhoneNumber {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("Phone number must be in E.164 format")
+    }
+}
+
+impl std::error::Error for InvalidPhoneNumber {
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///Phone number in E.164 format (e.g. `+14155552345`).
+pub struct PhoneNumber {
+    number: String,
+}
+
+impl PhoneNumber {
+    ///Maximum number of digits in E.164 number.
+    pub const MAX_DIGITS: usize = 15;
+
+    ///Parses number, which must be `+` followed by up to 15 digits, without leading zero.
+    pub fn parse(text: &str) -> Result<Self, InvalidPhoneNumber> {
+        let digits = match text.strip_prefix('+') {
+            Some(digits) => digits.as_bytes(),
+            None => return Err(InvalidPhoneNumber),
+        };
+
+        match digits.first() {
+            Some(b'1'..=b'9') if digits.len() <= Self::MAX_DIGITS && digits.iter().all(u8::is_ascii_digit) => Ok(Self {
+                number: text.to_owned(),
+            }),
+            _ => Err(InvalidPhoneNumber),
+        }
+    }
+
+    #[inline]
+    ///Returns number as string.
+    pub fn as_str(&self) -> &str {
+        self.number.as_str()
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = InvalidPhoneNumber;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl AsRef<str> for PhoneNumber {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PhoneNumber {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl Serialize for PhoneNumber {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> de::Deserialize<'de> for PhoneNumber {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let number = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        number.parse().map_err(de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Type of phone number available for purchase.
+pub enum PhoneNumberType {
+    ///Local (geographic) number.
+    Local,
+    ///Toll free number.
+    TollFree,
+    ///Mobile number.
+    Mobile,
+}
+
+impl PhoneNumberType {
+    #[inline]
+    ///Returns corresponding resource of `AvailablePhoneNumbers`.
+    pub const fn resource(&self) -> Resource {
+        match self {
+            PhoneNumberType::Local => Resource::Local,
+            PhoneNumberType::TollFree => Resource::TollFree,
+            PhoneNumberType::Mobile => Resource::Mobile,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error creating `NumberPattern`.
+pub struct InvalidNumberPattern;
+
+impl fmt::Display for InvalidNumberPattern {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("Number pattern must consist of digits, letters or '*'")
+    }
+}
+
+impl std::error::Error for InvalidNumberPattern {
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Pattern to match available phone numbers against, used as `Contains` filter.
+///
+///Pattern consists of digits, letters (matched against keypad) and `*` to match any single digit.
+pub struct NumberPattern<'a>(&'a str);
+
+impl<'a> NumberPattern<'a> {
+    ///Creates new pattern, validating its characters.
+    pub fn new(pattern: &'a str) -> Result<Self, InvalidNumberPattern> {
+        if !pattern.is_empty() && pattern.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'*') {
+            Ok(Self(pattern))
+        } else {
+            Err(InvalidNumberPattern)
+        }
+    }
+
+    #[inline]
+    ///Returns pattern as string.
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+///Geographic coordinates.
+pub struct LatLong {
+    ///Latitude
+    pub latitude: f64,
+    ///Longitude
+    pub longitude: f64,
+}
+
+impl fmt::Display for LatLong {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{},{}", self.latitude, self.longitude)
+    }
+}
+
+#[derive(Debug)]
+///Describes search of available phone numbers, suitable for urlencoded serialization
+///
+///Request is to be sent as query string via `GET` onto `AvailablePhoneNumberSearch::url`
+pub struct AvailablePhoneNumberSearch<'a> {
+    ///ISO country code of numbers
+    pub country: &'a str,
+    ///Type of numbers
+    pub kind: PhoneNumberType,
+    ///Area code of numbers, applicable to US and Canada only
+    pub area_code: Option<u16>,
+    ///Pattern numbers should contain
+    pub contains: Option<NumberPattern<'a>>,
+    ///Whether numbers must support SMS
+    pub sms_enabled: Option<bool>,
+    ///Whether numbers must support MMS
+    pub mms_enabled: Option<bool>,
+    ///Whether numbers must support voice
+    pub voice_enabled: Option<bool>,
+    ///Region (e.g. state) of numbers
+    pub in_region: Option<&'a str>,
+    ///Location numbers should be close to
+    pub near_lat_long: Option<LatLong>,
+    ///Distance in miles from `near_lat_long`, Twilio uses 25 by default
+    pub distance: Option<u16>,
+    ///Whether to exclude numbers, which require address
+    pub exclude_all_address_required: Option<bool>,
+}
+
+impl<'a> AvailablePhoneNumberSearch<'a> {
+    #[inline]
+    ///Creates search of `kind` numbers in `country` without filters.
+    pub const fn new(country: &'a str, kind: PhoneNumberType) -> Self {
+        Self {
+            country,
+            kind,
+            area_code: None,
+            contains: None,
+            sms_enabled: None,
+            mms_enabled: None,
+            voice_enabled: None,
+            in_region: None,
+            near_lat_long: None,
+            distance: None,
+            exclude_all_address_required: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to perform search onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::AvailablePhoneNumbers).segment(self.country).resource(self.kind.resource()).json()
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        if let Some(area_code) = self.area_code {
+            res.area_code(area_code);
+        }
+        if let Some(pattern) = self.contains {
+            res.contains(pattern);
+        }
+        if let Some(value) = self.sms_enabled {
+            res.sms_enabled(value);
+        }
+        if let Some(value) = self.mms_enabled {
+            res.mms_enabled(value);
+        }
+        if let Some(value) = self.voice_enabled {
+            res.voice_enabled(value);
+        }
+        if let Some(region) = self.in_region {
+            res.in_region(region);
+        }
+        if let Some(location) = self.near_lat_long {
+            res.near_lat_long(location);
+        }
+        if let Some(distance) = self.distance {
+            res.distance(distance);
+        }
+        if let Some(value) = self.exclude_all_address_required {
+            res.exclude_all_address_required(value);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for AvailablePhoneNumberSearch<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+///Capabilities of phone number.
+pub struct PhoneNumberCapabilities {
+    #[serde(default)]
+    ///Whether number can make and receive calls.
+    pub voice: bool,
+    #[serde(default, alias = "SMS")]
+    ///Whether number can send and receive SMS.
+    pub sms: bool,
+    #[serde(default, alias = "MMS")]
+    ///Whether number can send and receive MMS.
+    pub mms: bool,
+    #[serde(default)]
+    ///Whether number can send and receive fax.
+    pub fax: bool,
+}
+
+#[derive(Debug, Deserialize)]
+///Phone number, available for purchase.
+pub struct AvailablePhoneNumber {
+    ///Number in E.164 format.
+    pub phone_number: String,
+    ///Formatted number.
+    pub friendly_name: Option<String>,
+    ///Locality (e.g. city) of number.
+    pub locality: Option<String>,
+    ///Region (e.g. state) of number.
+    pub region: Option<String>,
+    ///Postal code of number.
+    pub postal_code: Option<String>,
+    ///ISO country code of number.
+    pub iso_country: Option<String>,
+    ///Local access and transport area of number.
+    pub lata: Option<String>,
+    ///Rate center of number.
+    pub rate_center: Option<String>,
+    ///Latitude of number.
+    pub latitude: Option<String>,
+    ///Longitude of number.
+    pub longitude: Option<String>,
+    ///Address requirement (`none`, `any`, `local` or `foreign`).
+    pub address_requirements: Option<String>,
+    #[serde(default)]
+    ///Whether number is new to Twilio platform.
+    pub beta: bool,
+    #[serde(default)]
+    ///Capabilities of number.
+    pub capabilities: PhoneNumberCapabilities,
+}
+
+#[derive(Debug, Deserialize)]
+///Result of available phone numbers search.
+pub struct AvailablePhoneNumberList {
+    ///Available numbers
+    pub available_phone_numbers: Vec<AvailablePhoneNumber>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+///Phone number, owned by account.
+pub struct IncomingPhoneNumber {
+    ///ID of phone number
+    pub sid: String,
+    ///ID of account, which owns phone number.
+    pub account_sid: Option<String>,
+    ///Number in E.164 format.
+    pub phone_number: String,
+    ///Name of phone number.
+    pub friendly_name: Option<String>,
+    #[serde(default)]
+    ///Capabilities of number.
+    pub capabilities: PhoneNumberCapabilities,
+    ///URL to invoke on incoming message.
+    pub sms_url: Option<String>,
+    ///HTTP method to invoke `sms_url` with.
+    pub sms_method: Option<TwilioMethod>,
+    ///URL to invoke when `sms_url` fails.
+    pub sms_fallback_url: Option<String>,
+    ///HTTP method to invoke `sms_fallback_url` with.
+    pub sms_fallback_method: Option<TwilioMethod>,
+    ///URL to invoke on incoming call.
+    pub voice_url: Option<String>,
+    ///HTTP method to invoke `voice_url` with.
+    pub voice_method: Option<TwilioMethod>,
+    ///URL to invoke when `voice_url` fails.
+    pub voice_fallback_url: Option<String>,
+    ///HTTP method to invoke `voice_fallback_url` with.
+    pub voice_fallback_method: Option<TwilioMethod>,
+    ///URL to post call status information onto.
+    pub status_callback: Option<String>,
+    ///HTTP method to invoke `status_callback` with.
+    pub status_callback_method: Option<TwilioMethod>,
+    ///Address requirement (`none`, `any`, `local` or `foreign`).
+    pub address_requirements: Option<String>,
+    ///Origin of number (`twilio` or `hosted`).
+    pub origin: Option<String>,
+    ///Status of number.
+    pub status: Option<String>,
+    #[serde(default)]
+    ///Whether number is new to Twilio platform.
+    pub beta: bool,
+    ///Timestamp (including zone) of when number is purchased.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp (including zone) of when number is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+}
+
+impl IncomingPhoneNumber {
+    #[inline]
+    ///Creates URL to list or purchase phone numbers of the account.
+    pub const fn list_url(host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::IncomingPhoneNumbers).json()
+    }
+
+    #[inline]
+    ///Creates URL to fetch, update or release phone number `sid`.
+    ///
+    ///Phone number is released by `DELETE` request onto this URL.
+    pub const fn url(host: &Host<'_>, account_sid: &str, sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::IncomingPhoneNumbers).sid(sid).json()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of owned phone numbers.
+pub struct IncomingPhoneNumberList {
+    ///Phone numbers
+    pub incoming_phone_numbers: Vec<IncomingPhoneNumber>,
+    #[serde(flatten)]
+    ///Paging information.
+    pub page: PageMeta,
+}
+
+#[derive(Debug, Default)]
+///Configuration of owned phone number.
+pub struct PhoneNumberConfig<'a> {
+    ///Name of phone number
+    pub friendly_name: Option<&'a str>,
+    ///URL to invoke on incoming message
+    pub sms_url: Option<&'a str>,
+    ///HTTP method to invoke `sms_url` with
+    pub sms_method: Option<TwilioMethod>,
+    ///URL to invoke when `sms_url` fails
+    pub sms_fallback_url: Option<&'a str>,
+    ///HTTP method to invoke `sms_fallback_url` with
+    pub sms_fallback_method: Option<TwilioMethod>,
+    ///URL to invoke on incoming call
+    pub voice_url: Option<&'a str>,
+    ///HTTP method to invoke `voice_url` with
+    pub voice_method: Option<TwilioMethod>,
+    ///URL to invoke when `voice_url` fails
+    pub voice_fallback_url: Option<&'a str>,
+    ///HTTP method to invoke `voice_fallback_url` with
+    pub voice_fallback_method: Option<TwilioMethod>,
+    ///URL to post call status information onto
+    pub status_callback: Option<&'a str>,
+    ///HTTP method to invoke `status_callback` with
+    pub status_callback_method: Option<TwilioMethod>,
+}
+
+impl<'a> PhoneNumberConfig<'a> {
+    #[inline]
+    ///Creates empty configuration.
+    pub const fn new() -> Self {
+        Self {
+            friendly_name: None,
+            sms_url: None,
+            sms_method: None,
+            sms_fallback_url: None,
+            sms_fallback_method: None,
+            voice_url: None,
+            voice_method: None,
+            voice_fallback_url: None,
+            voice_fallback_method: None,
+            status_callback: None,
+            status_callback_method: None,
+        }
+    }
+
+    fn write(&self, res: &mut TwilioRequest) {
+        if let Some(name) = self.friendly_name {
+            res.friendly_name(name);
+        }
+        match (self.sms_url, self.sms_method) {
+            (Some(url), Some(method)) => res.sms_url_with_method(method, url),
+            (Some(url), None) => res.sms_url(url),
+            (None, Some(method)) => res.add_pair("SmsMethod", method.as_str()),
+            (None, None) => res,
+        };
+        match (self.sms_fallback_url, self.sms_fallback_method) {
+            (Some(url), Some(method)) => res.sms_fallback_url_with_method(method, url),
+            (Some(url), None) => res.sms_fallback_url(url),
+            (None, Some(method)) => res.add_pair("SmsFallbackMethod", method.as_str()),
+            (None, None) => res,
+        };
+        match (self.voice_url, self.voice_method) {
+            (Some(url), Some(method)) => res.voice_url_with_method(method, url),
+            (Some(url), None) => res.voice_url(url),
+            (None, Some(method)) => res.add_pair("VoiceMethod", method.as_str()),
+            (None, None) => res,
+        };
+        match (self.voice_fallback_url, self.voice_fallback_method) {
+            (Some(url), Some(method)) => res.voice_fallback_url_with_method(method, url),
+            (Some(url), None) => res.voice_fallback_url(url),
+            (None, Some(method)) => res.add_pair("VoiceFallbackMethod", method.as_str()),
+            (None, None) => res,
+        };
+        match (self.status_callback, self.status_callback_method) {
+            (Some(url), Some(method)) => res.status_url_with_method(method, url),
+            (Some(url), None) => res.status_url(url),
+            (None, Some(method)) => res.add_pair("StatusCallbackMethod", method.as_str()),
+            (None, None) => res,
+        };
+    }
+}
+
+#[derive(Debug)]
+///Describes purchase of phone number, suitable for urlencoded serialization
+///
+///Request is to be posted onto `IncomingPhoneNumber::list_url`
+pub struct IncomingPhoneNumberCreate<'a> {
+    ///Number to purchase in E.164 format, usually found via `AvailablePhoneNumberSearch`
+    pub phone_number: &'a str,
+    ///Configuration of number
+    pub config: PhoneNumberConfig<'a>,
+}
+
+impl<'a> IncomingPhoneNumberCreate<'a> {
+    #[inline]
+    ///Creates purchase of `phone_number` without configuration.
+    pub const fn new(phone_number: &'a str) -> Self {
+        Self {
+            phone_number,
+            config: PhoneNumberConfig::new(),
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post purchase onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        IncomingPhoneNumber::list_url(host, account_sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.phone_number(self.phone_number);
+        self.config.write(&mut res);
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for IncomingPhoneNumberCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug)]
+///Describes modification of owned phone number, suitable for urlencoded serialization
+///
+///Request is to be posted onto `IncomingPhoneNumber::url`
+pub struct IncomingPhoneNumberUpdate<'a> {
+    ///ID of phone number
+    pub sid: &'a str,
+    ///Configuration to change
+    pub config: PhoneNumberConfig<'a>,
+}
+
+impl<'a> IncomingPhoneNumberUpdate<'a> {
+    #[inline]
+    ///Creates update of phone number `sid` without any modification.
+    pub const fn new(sid: &'a str) -> Self {
+        Self {
+            sid,
+            config: PhoneNumberConfig::new(),
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post update onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        IncomingPhoneNumber::url(host, account_sid, self.sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        self.config.write(&mut res);
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for IncomingPhoneNumberUpdate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
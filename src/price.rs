@@ -0,0 +1,391 @@
+//! Price representation without floating point.
+
+use core::{fmt, ops};
+use core::str::FromStr;
+use core::convert::TryFrom;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use super::{SmsResult, CallResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error parsing `Decimal` or `Currency`
+pub struct PriceParseError;
+
+impl fmt::Display for PriceParseError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("Invalid price")
+    }
+}
+
+impl std::error::Error for PriceParseError {
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///Fixed point decimal number with `Decimal::SCALE` fractional digits.
+///
+///Arithmetic operators saturate on overflow, use `checked_add` or `checked_sub` to detect it.
+pub struct Decimal {
+    units: i64,
+}
+
+impl Decimal {
+    ///Number of fractional digits.
+    pub const SCALE: u32 = 8;
+    ///Number of units in `1`.
+    pub const ONE: i64 = 10i64.pow(Self::SCALE);
+    ///Zero value.
+    pub const ZERO: Decimal = Decimal::from_units(0);
+
+    #[inline]
+    ///Creates value from number of `10^-SCALE` units.
+    pub const fn from_units(units: i64) -> Self {
+        Self {
+            units
+        }
+    }
+
+    #[inline]
+    ///Returns number of `10^-SCALE` units.
+    pub const fn units(&self) -> i64 {
+        self.units
+    }
+
+    #[inline]
+    ///Returns whether value is negative.
+    pub const fn is_negative(&self) -> bool {
+        self.units < 0
+    }
+
+    #[inline]
+    ///Returns absolute value, saturating at `i64::MAX` units.
+    pub const fn abs(&self) -> Self {
+        Self::from_units(self.units.saturating_abs())
+    }
+
+    #[inline]
+    ///Adds `other`, returning `None` on overflow.
+    pub const fn checked_add(&self, other: Self) -> Option<Self> {
+        match self.units.checked_add(other.units) {
+            Some(units) => Some(Self::from_units(units)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    ///Subtracts `other`, returning `None` on overflow.
+    pub const fn checked_sub(&self, other: Self) -> Option<Self> {
+        match self.units.checked_sub(other.units) {
+            Some(units) => Some(Self::from_units(units)),
+            None => None,
+        }
+    }
+}
+
+impl ops::Add for Decimal {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Self::from_units(self.units.saturating_add(other.units))
+    }
+}
+
+impl ops::AddAssign for Decimal {
+    #[inline(always)]
+    fn add_assign(&mut self, other: Self) {
+        self.units = self.units.saturating_add(other.units);
+    }
+}
+
+impl ops::Sub for Decimal {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        Self::from_units(self.units.saturating_sub(other.units))
+    }
+}
+
+impl ops::Neg for Decimal {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self::from_units(self.units.saturating_neg())
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = PriceParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let (is_negative, text) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+
+        let (integer, fraction) = match text.find('.') {
+            Some(idx) => (&text[..idx], &text[idx + 1..]),
+            None => (text, ""),
+        };
+
+        if (integer.is_empty() && fraction.is_empty()) || !integer.bytes().chain(fraction.bytes()).all(|byte| byte.is_ascii_digit()) {
+            return Err(PriceParseError);
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > Self::SCALE as usize {
+            return Err(PriceParseError);
+        }
+
+        let mut units = 0i64;
+        for digit in integer.bytes() {
+            units = units.checked_mul(10).and_then(|units| units.checked_add((digit - b'0') as i64)).ok_or(PriceParseError)?;
+        }
+        units = units.checked_mul(Self::ONE).ok_or(PriceParseError)?;
+
+        let mut multiplier = Self::ONE;
+        for digit in fraction.bytes() {
+            multiplier /= 10;
+            units = ((digit - b'0') as i64).checked_mul(multiplier).and_then(|digit| units.checked_add(digit)).ok_or(PriceParseError)?;
+        }
+
+        match is_negative {
+            true => Ok(Self::from_units(-units)),
+            false => Ok(Self::from_units(units)),
+        }
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let integer = self.units.unsigned_abs() / Self::ONE as u64;
+        let fraction = self.units.unsigned_abs() % Self::ONE as u64;
+
+        if self.is_negative() {
+            fmt.write_str("-")?;
+        }
+        fmt.write_fmt(format_args!("{}", integer))?;
+
+        if fraction != 0 {
+            let mut digits = str_buf::StrBuf::<{ Decimal::SCALE as usize }>::new();
+            let _ = fmt::Write::write_fmt(&mut digits, format_args!("{:0width$}", fraction, width = Self::SCALE as usize));
+            fmt.write_str(".")?;
+            fmt.write_str(digits.as_str().trim_end_matches('0'))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for Decimal {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+struct DecimalVisitor;
+
+impl<'de> Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    #[inline(always)]
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("decimal number")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+        text.parse().map_err(de::Error::custom)
+    }
+
+    #[inline]
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        match value.checked_mul(Decimal::ONE) {
+            Some(units) => Ok(Decimal::from_units(units)),
+            None => Err(de::Error::custom(PriceParseError)),
+        }
+    }
+
+    #[inline]
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        match i64::try_from(value) {
+            Ok(value) => self.visit_i64(value),
+            Err(_) => Err(de::Error::custom(PriceParseError)),
+        }
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Decimal {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///ISO 4217 currency code (e.g. `USD`).
+pub struct Currency {
+    code: [u8; 3],
+}
+
+impl Currency {
+    ///US Dollar
+    pub const USD: Currency = Currency { code: *b"USD" };
+    ///Euro
+    pub const EUR: Currency = Currency { code: *b"EUR" };
+    ///Pound Sterling
+    pub const GBP: Currency = Currency { code: *b"GBP" };
+
+    #[inline]
+    ///Returns currency code.
+    pub fn as_str(&self) -> &str {
+        //Only ASCII letters are allowed on creation
+        core::str::from_utf8(&self.code).unwrap_or_default()
+    }
+}
+
+impl FromStr for Currency {
+    type Err = PriceParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim().as_bytes();
+        if text.len() != 3 || !text.iter().all(u8::is_ascii_alphabetic) {
+            return Err(PriceParseError);
+        }
+
+        let mut code = [0u8; 3];
+        code.copy_from_slice(text);
+        code.make_ascii_uppercase();
+        Ok(Self {
+            code
+        })
+    }
+}
+
+impl fmt::Display for Currency {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Currency {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Currency {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        code.parse().map_err(de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+///Price of resource.
+///
+///Deserialized from `price` and `price_unit` fields of resource.
+///Twilio reports charges as negative values.
+pub struct Price {
+    #[serde(rename = "price")]
+    ///Amount
+    pub amount: Decimal,
+    #[serde(rename = "price_unit")]
+    ///Currency of amount
+    pub currency: Currency,
+}
+
+#[derive(Deserialize)]
+struct RawPrice {
+    #[serde(default)]
+    price: Option<Decimal>,
+    #[serde(default)]
+    price_unit: Option<Currency>,
+}
+
+//Deserializes optional `price` and `price_unit` fields of resource, to be used with `#[serde(flatten)]`.
+//
+//Price is absent until it is known, while `price_unit` is usually present regardless.
+//Malformed values are reported as error.
+pub(crate) fn deserialize_optional_price<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Price>, D::Error> {
+    let raw = RawPrice::deserialize(deserializer)?;
+    match (raw.price, raw.price_unit) {
+        (Some(amount), Some(currency)) => Ok(Some(Price { amount, currency })),
+        (Some(_), None) => Err(de::Error::missing_field("price_unit")),
+        (None, _) => Ok(None),
+    }
+}
+
+impl fmt::Display for Price {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_fmt(format_args!("{} {}", self.amount, self.currency))
+    }
+}
+
+#[derive(Debug, Default)]
+///Totals of prices per currency.
+pub struct CostLedger {
+    totals: HashMap<Currency, Decimal>,
+}
+
+impl CostLedger {
+    #[inline]
+    ///Creates empty ledger.
+    pub fn new() -> Self {
+        Self {
+            totals: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    ///Adds `price` to the total of its currency.
+    ///
+    ///Total saturates on overflow.
+    pub fn add(&mut self, price: &Price) {
+        *self.totals.entry(price.currency).or_default() += price.amount;
+    }
+
+    #[inline]
+    ///Adds price of SMS, if known.
+    pub fn add_sms(&mut self, sms: &SmsResult) {
+        if let Some(price) = sms.price.as_ref() {
+            self.add(price);
+        }
+    }
+
+    #[inline]
+    ///Adds price of call, if known.
+    pub fn add_call(&mut self, call: &CallResult) {
+        if let Some(price) = call.price.as_ref() {
+            self.add(price);
+        }
+    }
+
+    #[inline]
+    ///Returns total of specified `currency`.
+    pub fn total(&self, currency: Currency) -> Decimal {
+        self.totals.get(&currency).copied().unwrap_or_default()
+    }
+
+    #[inline]
+    ///Returns iterator over totals of all currencies.
+    pub fn iter(&self) -> impl Iterator<Item = Price> + '_ {
+        self.totals.iter().map(|(currency, amount)| Price {
+            amount: *amount,
+            currency: *currency,
+        })
+    }
+}
@@ -0,0 +1,281 @@
+//! Queue and queue member resources.
+
+use serde::Deserialize;
+
+use super::{Host, ResourcePath, Resource, ResourceUrl, ResultDateTime, PageMeta, TwilioRequest, TwilioMethod};
+
+///SID to address member at the front of the queue, instead of call's actual ID.
+pub const FRONT_MEMBER: &str = "Front";
+
+#[derive(Debug, Deserialize)]
+///Queue resource.
+pub struct Queue {
+    ///ID of queue
+    pub sid: String,
+    ///ID of account, which owns queue.
+    pub account_sid: Option<String>,
+    ///Name of queue.
+    pub friendly_name: Option<String>,
+    #[serde(default)]
+    ///Number of calls currently in queue.
+    pub current_size: u32,
+    #[serde(default)]
+    ///Maximum number of calls allowed in queue.
+    pub max_size: u32,
+    #[serde(default)]
+    ///Average wait time of calls in queue, in seconds.
+    pub average_wait_time: u32,
+    ///Timestamp (including zone) of when queue is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp (including zone) of when queue is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+}
+
+impl Queue {
+    #[inline]
+    ///Creates URL to list or create queues of the account.
+    pub const fn list_url(host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Queues).json()
+    }
+
+    #[inline]
+    ///Creates URL to fetch, update or delete queue `sid`.
+    pub const fn url(host: &Host<'_>, account_sid: &str, sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Queues).sid(sid).json()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of queues.
+pub struct QueueList {
+    ///Queues
+    pub queues: Vec<Queue>,
+    #[serde(flatten)]
+    ///Paging information.
+    pub page: PageMeta,
+}
+
+#[derive(Debug)]
+///Describes new queue, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Queue::list_url`
+pub struct QueueCreate<'a> {
+    ///Name of queue, unique within account
+    pub friendly_name: &'a str,
+    ///Maximum number of calls allowed in queue, Twilio uses 100 by default
+    pub max_size: Option<u32>,
+}
+
+impl<'a> QueueCreate<'a> {
+    #[inline]
+    ///Creates queue with default size.
+    pub const fn new(friendly_name: &'a str) -> Self {
+        Self {
+            friendly_name,
+            max_size: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post queue onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        Queue::list_url(host, account_sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.friendly_name(self.friendly_name);
+        if let Some(max_size) = self.max_size {
+            res.max_size(max_size);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for QueueCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Queue member resource.
+pub struct QueueMember {
+    ///ID of enqueued call
+    pub call_sid: String,
+    ///ID of queue
+    pub queue_sid: Option<String>,
+    #[serde(default)]
+    ///Position in queue, starting from 1.
+    pub position: u32,
+    #[serde(default)]
+    ///Time spent in queue, in seconds.
+    pub wait_time: u32,
+    ///Timestamp (including zone) of when call is enqueued.
+    pub date_enqueued: Option<ResultDateTime>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+}
+
+impl QueueMember {
+    #[inline]
+    ///Creates URL to list members of queue `queue_sid`.
+    pub const fn list_url(host: &Host<'_>, account_sid: &str, queue_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Queues).sid(queue_sid).resource(Resource::Members).json()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or dequeue member `call_sid` of queue `queue_sid`.
+    ///
+    ///Use `FRONT_MEMBER` as `call_sid` to address member at the front of the queue.
+    pub const fn url(host: &Host<'_>, account_sid: &str, queue_sid: &str, call_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Queues).sid(queue_sid).resource(Resource::Members).sid(call_sid).json()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of queue members.
+pub struct QueueMemberList {
+    ///Members
+    pub queue_members: Vec<QueueMember>,
+    #[serde(flatten)]
+    ///Paging information.
+    pub page: PageMeta,
+}
+
+#[derive(Debug)]
+///Describes dequeue of queue member, suitable for urlencoded serialization
+///
+///Request is to be posted onto `QueueMember::url`
+pub struct QueueDequeue<'a> {
+    ///ID of queue
+    pub queue_sid: &'a str,
+    ///ID of enqueued call, or `FRONT_MEMBER`
+    pub call_sid: &'a str,
+    ///URL of TwiML to execute on dequeued call
+    pub url: &'a str,
+    ///HTTP method to access `url`, Twilio uses `POST` by default
+    pub method: Option<TwilioMethod>,
+}
+
+impl<'a> QueueDequeue<'a> {
+    #[inline]
+    ///Creates dequeue of member at the front of queue `queue_sid`.
+    pub const fn front(queue_sid: &'a str, url: &'a str) -> Self {
+        Self {
+            queue_sid,
+            call_sid: FRONT_MEMBER,
+            url,
+            method: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post dequeue onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        QueueMember::url(host, account_sid, self.queue_sid, self.call_sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        match self.method {
+            Some(method) => res.url_with_method(method, self.url),
+            None => res.url(self.url),
+        };
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for QueueDequeue<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+///Payload of request onto `waitUrl` of `<Enqueue>`.
+pub struct EnqueueWaitCallback {
+    ///ID of enqueued call.
+    pub call_sid: String,
+    ///ID of account.
+    pub account_sid: Option<String>,
+    ///ID of queue.
+    pub queue_sid: Option<String>,
+    ///Position in queue, starting from 1.
+    pub queue_position: Option<u32>,
+    ///Time spent in queue, in seconds.
+    pub queue_time: Option<u32>,
+    ///Average wait time of calls in queue, in seconds.
+    pub avg_queue_time: Option<u32>,
+    ///Number of calls currently in queue.
+    pub current_queue_size: Option<u32>,
+    ///Maximum number of calls allowed in queue.
+    pub max_queue_size: Option<u32>,
+    ///Originator of call.
+    pub from: Option<String>,
+    ///Destination of call.
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Outcome of `<Enqueue>`.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum QueueResult {
+    ///Call is dequeued and bridged.
+    Bridged,
+    ///Call is dequeued and is being bridged.
+    BridgingInProcess,
+    ///Queue is full, call is not enqueued.
+    QueueFull,
+    ///Call is redirected out of queue.
+    Redirected,
+    ///Caller hung up.
+    Hangup,
+    ///Call left queue via `<Leave>`.
+    Leave,
+    ///Call could not be enqueued.
+    Error,
+    ///Internal Twilio error.
+    SystemError,
+    ///Result, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(QueueResult {
+    Bridged => "bridged",
+    BridgingInProcess => "bridging-in-process",
+    QueueFull => "queue-full",
+    Redirected => "redirected",
+    Hangup => "hangup",
+    Leave => "leave",
+    Error => "error",
+    SystemError => "system-error",
+});
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+///Payload of request onto `action` of `<Enqueue>`, made when call leaves queue.
+pub struct EnqueueActionCallback {
+    ///ID of enqueued call.
+    pub call_sid: String,
+    ///ID of account.
+    pub account_sid: Option<String>,
+    ///ID of queue.
+    pub queue_sid: Option<String>,
+    ///Outcome of enqueue.
+    pub queue_result: QueueResult,
+    ///Time spent in queue, in seconds.
+    pub queue_time: Option<u32>,
+    ///Originator of call.
+    pub from: Option<String>,
+    ///Destination of call.
+    pub to: Option<String>,
+}
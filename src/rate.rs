@@ -0,0 +1,187 @@
+//! Client-side rate limiting of outgoing messages.
+//!
+//! Twilio queues messages per sender and sends them with fixed throughput (MPS, messages per second).
+//! Messages that are queued for longer than their `ValidityPeriod` are dropped.
+//!
+//! Limiter makes no I/O on its own, user provides current time as duration since arbitrary,
+//! but monotonic, point in time.
+
+use core::fmt;
+use core::time::Duration;
+use std::collections::HashMap;
+
+use super::{Sms, Mms, TwilioRequest};
+
+///Default `ValidityPeriod` of message in seconds, used by Twilio.
+pub const DEFAULT_VALIDITY_PERIOD: u16 = 36_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Throughput of sender.
+pub enum SenderThroughput {
+    ///Long code, limited by 1 MPS.
+    LongCode,
+    ///Toll-free number, limited by 3 MPS.
+    TollFree,
+    ///Short code, limited by 100 MPS.
+    ShortCode,
+    ///Custom MPS, e.g. when upgraded throughput is purchased.
+    Custom(u32),
+}
+
+impl SenderThroughput {
+    #[inline]
+    ///Returns number of messages per second.
+    pub const fn mps(&self) -> u32 {
+        match self {
+            SenderThroughput::LongCode => 1,
+            SenderThroughput::TollFree => 3,
+            SenderThroughput::ShortCode => 100,
+            SenderThroughput::Custom(mps) => *mps,
+        }
+    }
+}
+
+impl Default for SenderThroughput {
+    #[inline(always)]
+    fn default() -> Self {
+        SenderThroughput::LongCode
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Time when message can be sent.
+pub struct SendSlot {
+    ///Time at which message leaves sender's queue.
+    pub at: Duration,
+    ///Time message spends in sender's queue.
+    pub wait: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Indicates that message would stay in queue for longer than its `ValidityPeriod`.
+pub struct ValidityExceeded {
+    ///Time message would spend in sender's queue.
+    pub wait: Duration,
+    ///Validity period of message in seconds.
+    pub validity_period: u16,
+}
+
+impl fmt::Display for ValidityExceeded {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_fmt(format_args!("Message would be queued for {}s, exceeding validity period of {}s", self.wait.as_secs(), self.validity_period))
+    }
+}
+
+impl std::error::Error for ValidityExceeded {
+}
+
+#[derive(Debug)]
+struct Bucket {
+    interval: Duration,
+    //Time at which next message leaves queue.
+    next: Duration,
+}
+
+impl Bucket {
+    fn new(throughput: SenderThroughput) -> Self {
+        let mps = core::cmp::max(throughput.mps(), 1);
+        Self {
+            interval: Duration::from_secs(1) / mps,
+            next: Duration::from_secs(0),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+///Token bucket scheduler of messages, keyed by sender (`From` or `MessagingServiceSid`).
+///
+///Each sender is able to send single message every `1 / MPS` seconds.
+///When message is scheduled, it reserves next free slot of its sender, unless wait would exceed validity period.
+pub struct RateLimiter {
+    default: SenderThroughput,
+    senders: HashMap<String, Bucket>,
+}
+
+impl RateLimiter {
+    #[inline]
+    ///Creates new limiter with `default` throughput for unknown senders.
+    pub fn new(default: SenderThroughput) -> Self {
+        Self {
+            default,
+            senders: HashMap::new(),
+        }
+    }
+
+    ///Sets throughput of specified `sender`.
+    pub fn set_throughput(&mut self, sender: &str, throughput: SenderThroughput) {
+        match self.senders.get_mut(sender) {
+            Some(bucket) => bucket.interval = Bucket::new(throughput).interval,
+            None => {
+                self.senders.insert(sender.to_owned(), Bucket::new(throughput));
+            }
+        }
+    }
+
+    ///Returns time message, sent at `now`, would spend in `sender`'s queue without reserving slot.
+    pub fn wait_time(&self, sender: &str, now: Duration) -> Duration {
+        match self.senders.get(sender) {
+            Some(bucket) => bucket.next.checked_sub(now).unwrap_or_default(),
+            None => Duration::from_secs(0),
+        }
+    }
+
+    ///Reserves slot for message of `sender` at `now`.
+    ///
+    ///If `validity_period` is not specified, then `DEFAULT_VALIDITY_PERIOD` is assumed.
+    ///
+    ///Returns error, without reserving slot, if message would be dropped by Twilio.
+    pub fn schedule(&mut self, sender: &str, now: Duration, validity_period: Option<u16>) -> Result<SendSlot, ValidityExceeded> {
+        let validity_period = validity_period.unwrap_or(DEFAULT_VALIDITY_PERIOD);
+        let wait = self.wait_time(sender, now);
+
+        if wait > Duration::from_secs(validity_period.into()) {
+            return Err(ValidityExceeded {
+                wait,
+                validity_period,
+            });
+        }
+
+        let default = self.default;
+        let bucket = match self.senders.get_mut(sender) {
+            Some(bucket) => bucket,
+            None => self.senders.entry(sender.to_owned()).or_insert_with(|| Bucket::new(default)),
+        };
+
+        let at = now + wait;
+        bucket.next = at + bucket.interval;
+        Ok(SendSlot {
+            at,
+            wait,
+        })
+    }
+
+    #[inline]
+    ///Reserves slot for `sms` at `now`.
+    pub fn schedule_sms(&mut self, sms: &Sms<'_>, now: Duration, validity_period: Option<u16>) -> Result<SendSlot, ValidityExceeded> {
+        self.schedule(sms.from.as_str(), now, validity_period)
+    }
+
+    #[inline]
+    ///Reserves slot for `mms` at `now`.
+    pub fn schedule_mms(&mut self, mms: &Mms<'_>, now: Duration, validity_period: Option<u16>) -> Result<SendSlot, ValidityExceeded> {
+        self.schedule_sms(&mms.sms, now, validity_period)
+    }
+
+    ///Reserves slot for `request` at `now`.
+    ///
+    ///Sender is `MessagingServiceSid`, if present, otherwise `From`.
+    ///Validity period is taken from `ValidityPeriod`.
+    ///
+    ///Returns `None` if request has no sender.
+    pub fn schedule_request(&mut self, request: &TwilioRequest, now: Duration) -> Option<Result<SendSlot, ValidityExceeded>> {
+        let sender = request.get_field("MessagingServiceSid").or_else(|| request.get_field("From"))?;
+        let validity_period = request.get_field("ValidityPeriod").and_then(|period| period.parse().ok());
+        Some(self.schedule(&sender, now, validity_period))
+    }
+}
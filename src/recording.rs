@@ -0,0 +1,480 @@
+//! Recording resources.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{Host, ResourcePath, Resource, ResourceUrl, Price, ResultDateTime, PageMeta, TwilioRequest};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Status of recording.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum RecordingStatus {
+    ///Recording is ongoing.
+    InProgress,
+    ///Recording is paused.
+    Paused,
+    ///Recording is stopped.
+    Stopped,
+    ///Recording is being processed.
+    Processing,
+    ///Recording is available.
+    Completed,
+    ///Recording is too short to be stored.
+    Absent,
+    ///Recording failed.
+    Failed,
+    ///Recording is deleted.
+    Deleted,
+    ///Status, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(RecordingStatus {
+    InProgress => "in-progress",
+    Paused => "paused",
+    Stopped => "stopped",
+    Processing => "processing",
+    Completed => "completed",
+    Absent => "absent",
+    Failed => "failed",
+    Deleted => "deleted",
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Way recording is created.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum RecordingSource {
+    ///`<Dial>` verb with `record` attribute.
+    DialVerb,
+    ///Conference with recording enabled.
+    Conference,
+    ///Outbound call with `Record` parameter.
+    OutboundApi,
+    ///Trunking with recording enabled.
+    Trunking,
+    ///`<Record>` verb.
+    RecordVerb,
+    ///Call recording API.
+    StartCallRecordingApi,
+    ///Conference recording API.
+    StartConferenceRecordingApi,
+    ///Source, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(RecordingSource {
+    DialVerb => "DialVerb",
+    Conference => "Conference",
+    OutboundApi => "OutboundAPI",
+    Trunking => "Trunking",
+    RecordVerb => "RecordVerb",
+    StartCallRecordingApi => "StartCallRecordingAPI",
+    StartConferenceRecordingApi => "StartConferenceRecordingAPI",
+});
+
+#[derive(Debug, Deserialize)]
+///Encryption of recording, when public key encryption is enabled.
+pub struct EncryptionDetails {
+    #[serde(rename = "encryption_public_key_sid")]
+    ///ID of public key used for encryption.
+    pub public_key_sid: Option<String>,
+    #[serde(rename = "encryption_cek")]
+    ///Encrypted content encryption key.
+    pub cek: Option<String>,
+    #[serde(rename = "encryption_iv")]
+    ///Initialization vector.
+    pub iv: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+///Recording resource.
+pub struct Recording {
+    ///ID of recording
+    pub sid: String,
+    ///ID of account, which owns recording.
+    pub account_sid: Option<String>,
+    ///API version used to create recording.
+    pub api_version: Option<String>,
+    ///ID of recorded call.
+    pub call_sid: Option<String>,
+    ///ID of recorded conference.
+    pub conference_sid: Option<String>,
+    ///Status of recording.
+    pub status: RecordingStatus,
+    ///Way recording is created.
+    pub source: Option<RecordingSource>,
+    #[serde(default, deserialize_with = "super::deserialize_optional_number_from_any")]
+    ///Duration in seconds.
+    pub duration: Option<i64>,
+    #[serde(default, deserialize_with = "super::deserialize_optional_number_from_any")]
+    ///Number of channels.
+    pub channels: Option<i64>,
+    ///Recorded track (`inbound`, `outbound` or `both`).
+    pub track: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::price::deserialize_optional_price")]
+    ///Cost of recording, if it is already known.
+    pub price: Option<Price>,
+    ///Error code, if recording failed.
+    pub error_code: Option<i64>,
+    ///Encryption of recording, if enabled.
+    pub encryption_details: Option<EncryptionDetails>,
+    ///Timestamp (including zone) of when recording is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp (including zone) of when recording is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///Timestamp (including zone) of when recording is started.
+    pub start_time: Option<ResultDateTime>,
+    ///URL of recording's media.
+    pub media_url: Option<String>,
+    ///URI of resource, relative to `https://api.twilio.com`
+    pub uri: Option<String>,
+    ///URIs of related resources, relative to `https://api.twilio.com`
+    pub subresource_uris: Option<HashMap<String, String>>,
+}
+
+impl Recording {
+    #[inline]
+    ///Creates URL to list recordings of the account.
+    pub const fn list_url(host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Recordings).json()
+    }
+
+    #[inline]
+    ///Creates URL to list recordings of the call.
+    pub const fn call_list_url(host: &Host<'_>, account_sid: &str, call_sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Calls).sid(call_sid).resource(Resource::Recordings).json()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or delete recording `sid`.
+    pub const fn url(host: &Host<'_>, account_sid: &str, sid: &str) -> ResourceUrl {
+        ResourcePath::with_host(host, account_sid).resource(Resource::Recordings).sid(sid).json()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of recordings.
+pub struct RecordingList {
+    ///Recordings
+    pub recordings: Vec<Recording>,
+    #[serde(flatten)]
+    ///Paging information.
+    pub page: PageMeta,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Audio format of recording media.
+pub enum RecordingFormat {
+    ///Binary WAV audio.
+    Wav,
+    ///MP3 audio.
+    Mp3,
+}
+
+impl RecordingFormat {
+    #[inline]
+    ///Returns file extension.
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            RecordingFormat::Wav => "wav",
+            RecordingFormat::Mp3 => "mp3",
+        }
+    }
+}
+
+///Creates URL to download media of recording `sid`.
+///
+///`requested_channels` can be set to `RecordingChannels::Dual` to download dual-channel recording with separate channels.
+pub const fn recording_media_url(host: &Host<'_>, account_sid: &str, sid: &str, format: RecordingFormat, requested_channels: Option<RecordingChannels>) -> ResourceUrl {
+    let url = ResourcePath::with_host(host, account_sid).resource(Resource::Recordings).sid(sid).extension(format.extension());
+    match requested_channels {
+        Some(RecordingChannels::Mono) => url.and("?RequestedChannels=1"),
+        Some(RecordingChannels::Dual) => url.and("?RequestedChannels=2"),
+        None => url,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+///Payload of recording status callback.
+pub struct RecordingStatusCallback {
+    ///ID of account.
+    pub account_sid: Option<String>,
+    ///ID of recorded call.
+    pub call_sid: Option<String>,
+    ///ID of recorded conference.
+    pub conference_sid: Option<String>,
+    ///ID of recording.
+    pub recording_sid: String,
+    ///URL of recording's media.
+    pub recording_url: Option<String>,
+    ///Status of recording.
+    pub recording_status: RecordingStatus,
+    ///Duration in seconds.
+    pub recording_duration: Option<u32>,
+    ///Number of channels.
+    pub recording_channels: Option<u32>,
+    ///Timestamp (including zone) of when recording is started.
+    pub recording_start_time: Option<ResultDateTime>,
+    ///Way recording is created.
+    pub recording_source: Option<RecordingSource>,
+    ///Recorded track (`inbound`, `outbound` or `both`).
+    pub recording_track: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserialize_optional_number_from_any")]
+    ///Error code, if recording failed.
+    pub error_code: Option<i64>,
+}
+
+///SID to address currently active recording of the call, instead of its actual ID.
+pub const CURRENT_RECORDING: &str = "Twilio.CURRENT";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Status to set on the live recording.
+pub enum RecordingUpdateStatus {
+    ///Pauses recording.
+    Paused,
+    ///Resumes paused recording.
+    InProgress,
+    ///Stops recording, which cannot be resumed afterwards.
+    Stopped,
+}
+
+impl RecordingUpdateStatus {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            RecordingUpdateStatus::Paused => "paused",
+            RecordingUpdateStatus::InProgress => "in-progress",
+            RecordingUpdateStatus::Stopped => "stopped",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Way to handle paused part of recording.
+pub enum PauseBehavior {
+    ///Paused part is omitted from recording.
+    Skip,
+    ///Paused part is replaced with silence.
+    Silence,
+}
+
+impl PauseBehavior {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PauseBehavior::Skip => "skip",
+            PauseBehavior::Silence => "silence",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Number of channels in recording.
+pub enum RecordingChannels {
+    ///Both legs are mixed into single channel.
+    Mono,
+    ///Each leg is recorded into separate channel.
+    Dual,
+}
+
+impl RecordingChannels {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            RecordingChannels::Mono => "mono",
+            RecordingChannels::Dual => "dual",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Audio track to record.
+pub enum RecordingTrack {
+    ///Audio received by Twilio.
+    Inbound,
+    ///Audio sent by Twilio.
+    Outbound,
+    ///Both tracks.
+    Both,
+}
+
+impl RecordingTrack {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            RecordingTrack::Inbound => "inbound",
+            RecordingTrack::Outbound => "outbound",
+            RecordingTrack::Both => "both",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Recording event to invoke status callback on.
+pub enum RecordingStatusCallbackEvent {
+    ///Recording is started or resumed.
+    InProgress,
+    ///Recording is available.
+    Completed,
+    ///Recording is too short to be stored.
+    Absent,
+}
+
+impl RecordingStatusCallbackEvent {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            RecordingStatusCallbackEvent::InProgress => "in-progress",
+            RecordingStatusCallbackEvent::Completed => "completed",
+            RecordingStatusCallbackEvent::Absent => "absent",
+        }
+    }
+}
+
+#[derive(Debug)]
+///Describes start of live call recording, suitable for urlencoded serialization
+///
+///Request is to be posted onto `<call base>/<call_sid>/Recordings.json`, see `CallRecording::url`
+pub struct CallRecording<'a> {
+    ///ID of call
+    pub call_sid: &'a str,
+    ///Number of channels
+    pub channels: Option<RecordingChannels>,
+    ///Audio track to record
+    pub track: Option<RecordingTrack>,
+    ///URL where to post recording status information
+    pub status_callback: Option<&'a str>,
+    ///Events to post recording status information on.
+    ///
+    ///If empty, Twilio notifies only about `completed` recording.
+    pub status_callback_events: &'a [RecordingStatusCallbackEvent],
+}
+
+impl<'a> CallRecording<'a> {
+    #[inline]
+    ///Creates recording of call `call_sid` with default parameters.
+    pub const fn new(call_sid: &'a str) -> Self {
+        Self {
+            call_sid,
+            channels: None,
+            track: None,
+            status_callback: None,
+            status_callback_events: &[],
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post recording onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourceUrl::new().and(host.get_call_base(account_sid).as_str()).and("/").and(self.call_sid).and("/Recordings.json")
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        if let Some(channels) = self.channels {
+            res.recording_channels(channels);
+        }
+        if let Some(track) = self.track {
+            res.recording_track(track);
+        }
+        if let Some(url) = self.status_callback {
+            res.recording_status_callback(url);
+        }
+        if !self.status_callback_events.is_empty() {
+            res.recording_status_callback_event(self.status_callback_events);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for CallRecording<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug)]
+///Describes modification of live call recording, suitable for urlencoded serialization
+///
+///Request is to be posted onto `<call base>/<call_sid>/Recordings/<sid>.json`, see `CallRecordingUpdate::url`
+pub struct CallRecordingUpdate<'a> {
+    ///ID of call
+    pub call_sid: &'a str,
+    ///ID of recording, or `CURRENT_RECORDING`
+    pub sid: &'a str,
+    ///New status of recording
+    pub status: RecordingUpdateStatus,
+    ///Way to handle paused part of recording, applicable only when pausing.
+    pub pause_behavior: Option<PauseBehavior>,
+}
+
+impl<'a> CallRecordingUpdate<'a> {
+    #[inline]
+    ///Creates update, pausing recording `sid`.
+    ///
+    ///Useful to prevent sensitive information, like payment details, from being recorded.
+    pub const fn pause(call_sid: &'a str, sid: &'a str, pause_behavior: PauseBehavior) -> Self {
+        Self {
+            call_sid,
+            sid,
+            status: RecordingUpdateStatus::Paused,
+            pause_behavior: Some(pause_behavior),
+        }
+    }
+
+    #[inline]
+    ///Creates update, resuming paused recording `sid`.
+    pub const fn resume(call_sid: &'a str, sid: &'a str) -> Self {
+        Self {
+            call_sid,
+            sid,
+            status: RecordingUpdateStatus::InProgress,
+            pause_behavior: None,
+        }
+    }
+
+    #[inline]
+    ///Creates update, stopping recording `sid`.
+    pub const fn stop(call_sid: &'a str, sid: &'a str) -> Self {
+        Self {
+            call_sid,
+            sid,
+            status: RecordingUpdateStatus::Stopped,
+            pause_behavior: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post update onto.
+    pub const fn url(&self, host: &Host<'_>, account_sid: &str) -> ResourceUrl {
+        ResourceUrl::new().and(host.get_call_base(account_sid).as_str()).and("/").and(self.call_sid).and("/Recordings/").and(self.sid).and(".json")
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.recording_status(self.status);
+        if let Some(pause_behavior) = self.pause_behavior {
+            res.pause_behavior(pause_behavior);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for CallRecordingUpdate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
@@ -0,0 +1,193 @@
+//! Retry policy for Twilio API requests.
+//!
+//! Policy makes no I/O on its own, it only inspects response and tells whenever request should
+//! be repeated and how long to wait before that.
+
+use core::time::Duration;
+
+use super::{TwilioRequest, TwilioError};
+
+///HTTP header with number of seconds to wait before repeating request.
+pub const RETRY_AFTER_HEADER: &str = "Retry-After";
+///Twilio error code indicating that too many requests were made.
+pub const TOO_MANY_REQUESTS_CODE: usize = 20429;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Outcome of `RetryPolicy` evaluation.
+pub enum RetryDecision {
+    ///Request should be repeated after specified delay.
+    Retry(Duration),
+    ///Request should not be repeated.
+    Stop,
+}
+
+impl RetryDecision {
+    #[inline(always)]
+    ///Returns delay if request is to be repeated.
+    pub fn delay(&self) -> Option<Duration> {
+        match self {
+            RetryDecision::Retry(delay) => Some(*delay),
+            RetryDecision::Stop => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+///Exponential backoff policy with jitter.
+///
+///Delay for attempt `N` (starting from 0) is `base_delay * 2^N`, limited by `max_delay`.
+///When jitter is enabled, actual delay is randomly chosen between half of it and full value.
+///
+///Requests are retried in following cases:
+///
+///- Status `429` or Twilio error code `20429`, as request is rejected without processing;
+///- Status `503`, as service is not available to process request;
+///- Status `500`, `502` or `504` and transport errors, but only when request has idempotency token, as
+///  Twilio might have processed it already.
+///
+///If response contains `Retry-After` with number of seconds, it takes priority over backoff,
+///but it is still limited by `max_delay`.
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    seed: u64,
+}
+
+impl RetryPolicy {
+    ///Creates default policy.
+    ///
+    ///Allows 3 retries starting with 500ms delay, limited by 30 seconds with jitter enabled.
+    ///
+    ///Seed of jitter is randomly chosen for each policy, so that clients do not retry in lockstep.
+    pub fn new() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            seed: random_seed(),
+        }
+    }
+
+    #[inline]
+    ///Sets maximum number of retries, after initial attempt.
+    pub const fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    #[inline]
+    ///Sets delay of the first retry.
+    pub const fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    #[inline]
+    ///Sets upper limit on delay.
+    pub const fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    #[inline]
+    ///Sets whether jitter is to be applied to the delay.
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    #[inline]
+    ///Sets seed of jitter.
+    ///
+    ///By default seed is random, this allows to make delays reproducible.
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    ///Calculates backoff delay for specified attempt.
+    ///
+    ///Attempt starts from 0, which is first retry.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let multiplier = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        let delay = match self.base_delay.checked_mul(multiplier) {
+            Some(delay) if delay < self.max_delay => delay,
+            _ => self.max_delay,
+        };
+
+        if self.jitter {
+            let delay_ms = delay.as_millis() as u64;
+            let half = delay_ms / 2;
+            let random = splitmix64(self.seed ^ (attempt as u64));
+            Duration::from_millis(half + random % (delay_ms - half + 1))
+        } else {
+            delay
+        }
+    }
+
+    ///Decides whether `request` is to be repeated, based on response to its `attempt`.
+    ///
+    ///- `attempt` - Number of already performed retries, starting from 0.
+    ///- `status` - HTTP status code of response.
+    ///- `headers` - HTTP headers of response as name and value pairs.
+    ///- `error` - Error from response body, if any.
+    pub fn check<'a, H: IntoIterator<Item = (&'a str, &'a str)>>(&self, attempt: u32, request: &TwilioRequest, status: u16, headers: H, error: Option<&TwilioError>) -> RetryDecision {
+        if attempt >= self.max_retries {
+            return RetryDecision::Stop;
+        }
+
+        let is_throttled = status == 429 || error.is_some_and(|error| error.code == TOO_MANY_REQUESTS_CODE);
+        let is_retryable = match status {
+            503 => true,
+            500 | 502 | 504 => request.is_idempotent(),
+            _ => is_throttled,
+        };
+
+        if !is_retryable {
+            return RetryDecision::Stop;
+        }
+
+        let retry_after = headers.into_iter()
+                                 .find(|(name, _)| name.eq_ignore_ascii_case(RETRY_AFTER_HEADER))
+                                 .and_then(|(_, value)| value.trim().parse::<u64>().ok());
+        match retry_after {
+            Some(secs) => RetryDecision::Retry(core::cmp::min(Duration::from_secs(secs), self.max_delay)),
+            None => RetryDecision::Retry(self.backoff(attempt)),
+        }
+    }
+
+    ///Decides whether `request` is to be repeated, when its `attempt` failed without response.
+    ///
+    ///As it is unknown whether Twilio received request, it is repeated only when request has idempotency token.
+    pub fn check_transport_error(&self, attempt: u32, request: &TwilioRequest) -> RetryDecision {
+        if attempt < self.max_retries && request.is_idempotent() {
+            RetryDecision::Retry(self.backoff(attempt))
+        } else {
+            RetryDecision::Stop
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//Uses randomly keyed hasher of std as source of entropy, as keys differ for each instance.
+fn random_seed() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    std::collections::hash_map::RandomState::new().build_hasher().finish()
+}
+
+fn splitmix64(mut state: u64) -> u64 {
+    state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    state ^ (state >> 31)
+}
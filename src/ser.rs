@@ -1,4 +1,4 @@
-use super::{Call, CallInstruction, Sms, Mms, TwilioRequest};
+use super::{Call, CallInstruction, Sender, Sms, Mms, TwilioRequest};
 
 use core::fmt;
 use std::borrow::Cow;
@@ -65,7 +65,10 @@ impl<'a> Serialize for Mms<'a> {
     fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let mut ser = serializer.serialize_struct("Sms", 3)?;
 
-        ser.serialize_field("From", &self.sms.from)?;
+        match self.sms.from {
+            Sender::Number(number) => ser.serialize_field("From", number)?,
+            Sender::MessagingService(sid) => ser.serialize_field("MessagingServiceSid", sid)?,
+        };
         ser.serialize_field("To", &self.sms.to)?;
         ser.serialize_field("Body", &self.sms.body)?;
         ser.serialize_field("MediaUrl", &self.media_url)?;
@@ -78,7 +81,10 @@ impl<'a> Serialize for Sms<'a> {
     fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let mut ser = serializer.serialize_struct("Sms", 3)?;
 
-        ser.serialize_field("From", &self.from)?;
+        match self.from {
+            Sender::Number(number) => ser.serialize_field("From", number)?,
+            Sender::MessagingService(sid) => ser.serialize_field("MessagingServiceSid", sid)?,
+        };
         ser.serialize_field("To", &self.to)?;
         ser.serialize_field("Body", &self.body)?;
         ser.end()
@@ -0,0 +1,245 @@
+//! Lifecycle of message and call statuses.
+
+use core::cmp;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{SmsStatus, CallStatus};
+
+///Status with known lifecycle.
+pub trait Lifecycle: Clone {
+    ///Returns whether status can be changed to `next`, directly or via intermediate statuses.
+    fn can_transition_to(&self, next: &Self) -> bool;
+}
+
+impl SmsStatus {
+    fn successors(&self) -> &'static [SmsStatus] {
+        match self {
+            SmsStatus::Accepted => &[SmsStatus::Scheduled, SmsStatus::Queued, SmsStatus::Failed],
+            SmsStatus::Scheduled => &[SmsStatus::Queued, SmsStatus::Canceled],
+            SmsStatus::Queued => &[SmsStatus::Sending, SmsStatus::Failed],
+            SmsStatus::Sending => &[SmsStatus::Sent, SmsStatus::Failed],
+            SmsStatus::Sent => &[SmsStatus::Delivered, SmsStatus::Undelivered, SmsStatus::PartiallyDelivered],
+            SmsStatus::Delivered => &[SmsStatus::Read],
+            SmsStatus::Receiving => &[SmsStatus::Received],
+            SmsStatus::Canceled | SmsStatus::Failed | SmsStatus::Undelivered | SmsStatus::PartiallyDelivered
+            | SmsStatus::Received | SmsStatus::Read | SmsStatus::Unknown(_) => &[],
+        }
+    }
+
+    #[inline]
+    ///Returns whether no further status changes are expected.
+    ///
+    ///Note that `Delivered` is not terminal, as it can be followed by `Read` on channels supporting read receipts.
+    ///Use `is_success` to check whether message reached its destination.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, SmsStatus::Canceled | SmsStatus::Failed | SmsStatus::Undelivered
+                       | SmsStatus::PartiallyDelivered | SmsStatus::Received | SmsStatus::Read)
+    }
+
+    #[inline]
+    ///Returns whether message reached its destination.
+    pub fn is_success(&self) -> bool {
+        matches!(self, SmsStatus::Delivered | SmsStatus::Read | SmsStatus::Received)
+    }
+}
+
+impl Lifecycle for SmsStatus {
+    fn can_transition_to(&self, next: &Self) -> bool {
+        self.successors().iter().any(|status| status == next || status.can_transition_to(next))
+    }
+}
+
+impl PartialOrd for SmsStatus {
+    ///Orders statuses by lifecycle, statuses of different branches are not comparable.
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        lifecycle_cmp(self, other)
+    }
+}
+
+impl CallStatus {
+    fn successors(&self) -> &'static [CallStatus] {
+        match self {
+            CallStatus::Queued => &[CallStatus::Initiated, CallStatus::Ringing, CallStatus::Canceled, CallStatus::Failed],
+            CallStatus::Initiated => &[CallStatus::Ringing, CallStatus::Canceled, CallStatus::Failed],
+            CallStatus::Ringing => &[CallStatus::InProgress, CallStatus::Canceled, CallStatus::Busy, CallStatus::NoAnswer, CallStatus::Failed],
+            CallStatus::InProgress => &[CallStatus::Completed],
+            CallStatus::Canceled | CallStatus::Completed | CallStatus::Busy | CallStatus::NoAnswer
+            | CallStatus::Failed | CallStatus::Unknown(_) => &[],
+        }
+    }
+
+    #[inline]
+    ///Returns whether call is over.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, CallStatus::Canceled | CallStatus::Completed | CallStatus::Busy | CallStatus::NoAnswer | CallStatus::Failed)
+    }
+
+    #[inline]
+    ///Returns whether call was answered and finished normally.
+    pub fn is_success(&self) -> bool {
+        matches!(self, CallStatus::Completed)
+    }
+}
+
+impl Lifecycle for CallStatus {
+    fn can_transition_to(&self, next: &Self) -> bool {
+        self.successors().iter().any(|status| status == next || status.can_transition_to(next))
+    }
+}
+
+impl PartialOrd for CallStatus {
+    ///Orders statuses by lifecycle, statuses of different branches are not comparable.
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        lifecycle_cmp(self, other)
+    }
+}
+
+fn lifecycle_cmp<T: Lifecycle + PartialEq>(left: &T, right: &T) -> Option<cmp::Ordering> {
+    if left == right {
+        Some(cmp::Ordering::Equal)
+    } else if left.can_transition_to(right) {
+        Some(cmp::Ordering::Less)
+    } else if right.can_transition_to(left) {
+        Some(cmp::Ordering::Greater)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+///Payload of message status callback.
+pub struct SmsStatusCallback {
+    ///ID of message.
+    pub message_sid: String,
+    ///New status of message.
+    pub message_status: SmsStatus,
+    ///ID of account.
+    pub account_sid: Option<String>,
+    ///Originator of message.
+    pub from: Option<String>,
+    ///Destination of message.
+    pub to: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserialize_optional_number_from_any")]
+    ///Error code, if message failed or undelivered.
+    pub error_code: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+///Payload of call status callback.
+pub struct CallStatusCallback {
+    ///ID of call.
+    pub call_sid: String,
+    ///New status of call.
+    pub call_status: CallStatus,
+    ///Order of callback, starting from 0.
+    pub sequence_number: Option<u32>,
+    ///ID of account.
+    pub account_sid: Option<String>,
+    ///Originator of call.
+    pub from: Option<String>,
+    ///Destination of call.
+    pub to: Option<String>,
+    ///Duration of call in seconds, when call is completed.
+    pub call_duration: Option<u32>,
+}
+
+#[derive(Debug)]
+struct Entry<S> {
+    status: S,
+    sequence: Option<u32>,
+}
+
+#[derive(Debug)]
+///Tracks latest status per SID, while receiving status updates out of order.
+///
+///Update is accepted when its sequence number is greater than the last one.
+///Without sequence number, update is accepted only if current status can transition to it.
+pub struct StatusTracker<S> {
+    entries: HashMap<String, Entry<S>>,
+}
+
+impl<S: Lifecycle> StatusTracker<S> {
+    #[inline]
+    ///Creates new instance.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    ///Updates status of `sid`, returning whether update is accepted.
+    pub fn update(&mut self, sid: &str, status: S, sequence: Option<u32>) -> bool {
+        let entry = match self.entries.get_mut(sid) {
+            Some(entry) => entry,
+            None => {
+                self.entries.insert(sid.to_owned(), Entry {
+                    status,
+                    sequence,
+                });
+                return true;
+            }
+        };
+
+        let is_newer = match (entry.sequence, sequence) {
+            (Some(current), Some(sequence)) => sequence > current,
+            _ => entry.status.can_transition_to(&status),
+        };
+
+        if is_newer {
+            entry.status = status;
+            entry.sequence = sequence.or(entry.sequence);
+        }
+        is_newer
+    }
+
+    #[inline]
+    ///Returns latest status of `sid`.
+    pub fn get(&self, sid: &str) -> Option<&S> {
+        self.entries.get(sid).map(|entry| &entry.status)
+    }
+
+    #[inline]
+    ///Stops tracking `sid`, returning its latest status.
+    pub fn remove(&mut self, sid: &str) -> Option<S> {
+        self.entries.remove(sid).map(|entry| entry.status)
+    }
+
+    #[inline]
+    ///Returns number of tracked SIDs.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    ///Returns whether nothing is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl StatusTracker<SmsStatus> {
+    #[inline]
+    ///Updates status from message status callback.
+    pub fn track_sms(&mut self, callback: &SmsStatusCallback) -> bool {
+        self.update(&callback.message_sid, callback.message_status.clone(), None)
+    }
+}
+
+impl StatusTracker<CallStatus> {
+    #[inline]
+    ///Updates status from call status callback.
+    pub fn track_call(&mut self, callback: &CallStatusCallback) -> bool {
+        self.update(&callback.call_sid, callback.call_status.clone(), callback.sequence_number)
+    }
+}
+
+impl<S: Lifecycle> Default for StatusTracker<S> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
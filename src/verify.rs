@@ -0,0 +1,1009 @@
+//! Verify v2 API `verify.twilio.com/v2`
+//!
+//! One-time passcodes are sent via `VerificationCreate` and checked via `VerificationCheckCreate`,
+//! both scoped by verification `Service`.
+//!
+//! Push and TOTP factors of entities (users) are created via `FactorCreate` and challenged via `ChallengeCreate`.
+
+use core::fmt::Write;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{encoder, Host, ProductPath, Product, ResourceUrl, ResultDateTime, ListMeta, TwilioRequest, TwilioDateTime};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Channel to deliver verification code.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum VerificationChannel {
+    ///SMS message.
+    Sms,
+    ///Voice call.
+    Call,
+    ///Email, requires mailer configuration of service.
+    Email,
+    ///WhatsApp message.
+    Whatsapp,
+    ///Silent network authentication.
+    Sna,
+    ///Channel, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(VerificationChannel {
+    Sms => "sms",
+    Call => "call",
+    Email => "email",
+    Whatsapp => "whatsapp",
+    Sna => "sna",
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Status of verification.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum VerificationStatus {
+    ///Code is sent, but not yet verified.
+    Pending,
+    ///Code is verified.
+    Approved,
+    ///Verification is canceled.
+    Canceled,
+    ///Too many wrong codes are provided.
+    MaxAttemptsReached,
+    ///Verification expired, after 10 minutes.
+    Expired,
+    ///Status, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(VerificationStatus {
+    Pending => "pending",
+    Approved => "approved",
+    Canceled => "canceled",
+    MaxAttemptsReached => "max_attempts_reached",
+    Expired => "expired",
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Status to set on pending verification.
+pub enum VerificationUpdateStatus {
+    ///Cancels verification.
+    Canceled,
+    ///Approves verification, which is verified outside of Twilio.
+    Approved,
+}
+
+impl VerificationUpdateStatus {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            VerificationUpdateStatus::Canceled => "canceled",
+            VerificationUpdateStatus::Approved => "approved",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Verification service resource.
+pub struct Service {
+    ///ID of service
+    pub sid: String,
+    ///ID of account, which owns service.
+    pub account_sid: Option<String>,
+    ///Name of service, shown in verification messages.
+    pub friendly_name: Option<String>,
+    ///Number of digits in verification code.
+    pub code_length: Option<u8>,
+    #[serde(default)]
+    ///Whether to perform lookup of phone number before verification.
+    pub lookup_enabled: bool,
+    #[serde(default)]
+    ///Whether to skip SMS verification of landlines.
+    pub skip_sms_to_landlines: bool,
+    #[serde(default)]
+    ///Whether to require pressing a key to hear code in voice call.
+    pub dtmf_input_required: bool,
+    #[serde(default)]
+    ///Whether to include warning not to share code.
+    pub do_not_share_warning_enabled: bool,
+    #[serde(default)]
+    ///Whether custom code can be provided.
+    pub custom_code_enabled: bool,
+    ///Timestamp of when service is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when service is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+}
+
+impl Service {
+    #[inline]
+    ///Creates URL to list or create services.
+    pub const fn list_url(host: &Host<'_>) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch, update or delete service `sid`.
+    pub const fn url(host: &Host<'_>, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").sid(sid).into_url()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Page of verification services.
+pub struct ServiceList {
+    ///Services
+    pub services: Vec<Service>,
+    ///Paging information.
+    pub meta: ListMeta,
+}
+
+#[derive(Debug)]
+///Describes new verification service, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Service::list_url`
+pub struct ServiceCreate<'a> {
+    ///Name of service, shown in verification messages
+    pub friendly_name: &'a str,
+    ///Number of digits in verification code, from 4 to 10
+    pub code_length: Option<u8>,
+    ///Whether to perform lookup of phone number before verification
+    pub lookup_enabled: Option<bool>,
+    ///Whether to skip SMS verification of landlines
+    pub skip_sms_to_landlines: Option<bool>,
+    ///Whether to require pressing a key to hear code in voice call
+    pub dtmf_input_required: Option<bool>,
+    ///Whether to include warning not to share code
+    pub do_not_share_warning_enabled: Option<bool>,
+    ///Whether custom code can be provided
+    pub custom_code_enabled: Option<bool>,
+}
+
+impl<'a> ServiceCreate<'a> {
+    #[inline]
+    ///Creates service with default parameters.
+    pub const fn new(friendly_name: &'a str) -> Self {
+        Self {
+            friendly_name,
+            code_length: None,
+            lookup_enabled: None,
+            skip_sms_to_landlines: None,
+            dtmf_input_required: None,
+            do_not_share_warning_enabled: None,
+            custom_code_enabled: None,
+        }
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.friendly_name(self.friendly_name);
+        if let Some(code_length) = self.code_length {
+            res.code_length(code_length);
+        }
+        let flags = [
+            ("LookupEnabled", self.lookup_enabled),
+            ("SkipSmsToLandlines", self.skip_sms_to_landlines),
+            ("DtmfInputRequired", self.dtmf_input_required),
+            ("DoNotShareWarningEnabled", self.do_not_share_warning_enabled),
+            ("CustomCodeEnabled", self.custom_code_enabled),
+        ];
+        for (field, value) in flags.iter() {
+            if let Some(value) = value {
+                res.add_bool(field, *value);
+            }
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for ServiceCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug)]
+///Describes start of verification, suitable for urlencoded serialization
+///
+///Request is to be posted onto `VerificationCreate::url`
+pub struct VerificationCreate<'a> {
+    ///ID of service
+    pub service_sid: &'a str,
+    ///Phone number in E.164 format or email address
+    pub to: &'a str,
+    ///Channel to deliver code
+    pub channel: VerificationChannel,
+    ///Locale of message (e.g. `en`)
+    pub locale: Option<&'a str>,
+    ///Name to use instead of service's name
+    pub custom_friendly_name: Option<&'a str>,
+    ///Values of service's rate limits, as pairs of unique name and value (e.g. user's IP)
+    pub rate_limits: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> VerificationCreate<'a> {
+    #[inline]
+    ///Creates verification of `to` via `channel`.
+    pub const fn new(service_sid: &'a str, to: &'a str, channel: VerificationChannel) -> Self {
+        Self {
+            service_sid,
+            to,
+            channel,
+            locale: None,
+            custom_friendly_name: None,
+            rate_limits: &[],
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post verification onto.
+    pub const fn url(&self, host: &Host<'_>) -> ResourceUrl {
+        Verification::list_url(host, self.service_sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.to(self.to).channel(&self.channel);
+        if let Some(locale) = self.locale {
+            res.locale(locale);
+        }
+        if let Some(name) = self.custom_friendly_name {
+            res.custom_friendly_name(name);
+        }
+        if !self.rate_limits.is_empty() {
+            res.rate_limits(self.rate_limits);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for VerificationCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug)]
+///Describes change of verification status, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Verification::url`
+pub struct VerificationUpdate<'a> {
+    ///ID of service
+    pub service_sid: &'a str,
+    ///ID of verification
+    pub sid: &'a str,
+    ///New status
+    pub status: VerificationUpdateStatus,
+}
+
+impl<'a> VerificationUpdate<'a> {
+    #[inline]
+    ///Creates update, canceling verification `sid`.
+    pub const fn cancel(service_sid: &'a str, sid: &'a str) -> Self {
+        Self {
+            service_sid,
+            sid,
+            status: VerificationUpdateStatus::Canceled,
+        }
+    }
+
+    #[inline]
+    ///Creates update, approving verification `sid` verified outside of Twilio.
+    pub const fn approve(service_sid: &'a str, sid: &'a str) -> Self {
+        Self {
+            service_sid,
+            sid,
+            status: VerificationUpdateStatus::Approved,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post update onto.
+    pub const fn url(&self, host: &Host<'_>) -> ResourceUrl {
+        Verification::url(host, self.service_sid, self.sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.verification_status(self.status);
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for VerificationUpdate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Attempt to deliver verification code.
+pub struct SendCodeAttempt {
+    ///ID of attempt.
+    pub attempt_sid: Option<String>,
+    ///Channel of attempt.
+    pub channel: Option<VerificationChannel>,
+    ///Timestamp of attempt.
+    pub time: Option<ResultDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+///Verification resource.
+pub struct Verification {
+    ///ID of verification
+    pub sid: String,
+    ///ID of service.
+    pub service_sid: Option<String>,
+    ///ID of account, which owns service.
+    pub account_sid: Option<String>,
+    ///Phone number or email address being verified.
+    pub to: Option<String>,
+    ///Channel used to deliver code.
+    pub channel: Option<VerificationChannel>,
+    ///Status of verification.
+    pub status: VerificationStatus,
+    #[serde(default)]
+    ///Whether verification is approved.
+    pub valid: bool,
+    #[serde(default)]
+    ///Attempts to deliver code.
+    pub send_code_attempts: Vec<SendCodeAttempt>,
+    ///Timestamp of when verification is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when verification is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+}
+
+impl Verification {
+    #[inline]
+    ///Creates URL to create verifications of service `service_sid`.
+    pub const fn list_url(host: &Host<'_>, service_sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").sid(service_sid).segment("Verifications").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or update verification `sid`.
+    pub const fn url(host: &Host<'_>, service_sid: &str, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").sid(service_sid).segment("Verifications").sid(sid).into_url()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+///Verification to check code against.
+pub enum VerificationTarget<'a> {
+    ///Phone number or email address, which verification was sent to.
+    To(&'a str),
+    ///ID of verification.
+    Sid(&'a str),
+}
+
+#[derive(Debug)]
+///Describes check of verification code, suitable for urlencoded serialization
+///
+///Request is to be posted onto `VerificationCheckCreate::url`
+pub struct VerificationCheckCreate<'a> {
+    ///ID of service
+    pub service_sid: &'a str,
+    ///Verification to check
+    pub target: VerificationTarget<'a>,
+    ///Code, provided by user
+    pub code: &'a str,
+}
+
+impl<'a> VerificationCheckCreate<'a> {
+    #[inline]
+    ///Creates check of `code` for `target` verification.
+    pub const fn new(service_sid: &'a str, target: VerificationTarget<'a>, code: &'a str) -> Self {
+        Self {
+            service_sid,
+            target,
+            code,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post check onto.
+    pub const fn url(&self, host: &Host<'_>) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").sid(self.service_sid).segment("VerificationCheck").into_url()
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        match self.target {
+            VerificationTarget::To(to) => res.to(to),
+            VerificationTarget::Sid(sid) => res.verification_sid(sid),
+        };
+        res.code(self.code);
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for VerificationCheckCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Result of verification check.
+pub struct VerificationCheck {
+    ///ID of verification
+    pub sid: String,
+    ///ID of service.
+    pub service_sid: Option<String>,
+    ///ID of account, which owns service.
+    pub account_sid: Option<String>,
+    ///Phone number or email address being verified.
+    pub to: Option<String>,
+    ///Channel used to deliver code.
+    pub channel: Option<VerificationChannel>,
+    ///Status of verification, `Approved` if code is correct.
+    pub status: VerificationStatus,
+    #[serde(default)]
+    ///Whether code is correct.
+    pub valid: bool,
+    ///Timestamp of when verification is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when verification is updated.
+    pub date_updated: Option<ResultDateTime>,
+}
+
+impl VerificationCheck {
+    #[inline]
+    ///Returns whether code is approved.
+    pub fn is_approved(&self) -> bool {
+        self.status == VerificationStatus::Approved
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Type of multi-factor authentication factor.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum FactorType {
+    ///Push notification to mobile application.
+    Push,
+    ///Time-based one-time password.
+    Totp,
+    ///Factor type, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(FactorType {
+    Push => "push",
+    Totp => "totp",
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Status of factor.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum FactorStatus {
+    ///Factor is created, but not yet verified.
+    Unverified,
+    ///Factor is verified and can be used for challenges.
+    Verified,
+    ///Status, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(FactorStatus {
+    Unverified => "unverified",
+    Verified => "verified",
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Status of challenge.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum ChallengeStatus {
+    ///Challenge awaits response.
+    Pending,
+    ///Challenge is not responded in time.
+    Expired,
+    ///Challenge is approved.
+    Approved,
+    ///Challenge is denied.
+    Denied,
+    ///Status, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(ChallengeStatus {
+    Pending => "pending",
+    Expired => "expired",
+    Approved => "approved",
+    Denied => "denied",
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Hash algorithm of TOTP factor.
+pub enum TotpAlgorithm {
+    ///SHA-1, used by default.
+    Sha1,
+    ///SHA-256.
+    Sha256,
+    ///SHA-512.
+    Sha512,
+}
+
+impl TotpAlgorithm {
+    #[inline]
+    ///Returns textual representation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            TotpAlgorithm::Sha1 => "sha1",
+            TotpAlgorithm::Sha256 => "sha256",
+            TotpAlgorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+///Configuration of push factor.
+pub struct PushFactor<'a> {
+    ///Public key of device in base64, sent as `Binding.PublicKey`
+    pub public_key: &'a str,
+    ///Version of Verify SDK, sent as `Config.SdkVersion`
+    pub sdk_version: &'a str,
+    ///ID of mobile application, sent as `Config.AppId`
+    pub app_id: &'a str,
+    ///Push platform (`apn`, `fcm` or `none`), sent as `Config.NotificationPlatform`
+    pub notification_platform: &'a str,
+    ///Push token of device, sent as `Config.NotificationToken`
+    pub notification_token: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+///Configuration of TOTP factor.
+pub struct TotpFactor<'a> {
+    ///Shared secret in base32, sent as `Binding.Secret`, Twilio generates it when omitted
+    pub secret: Option<&'a str>,
+    ///Number of seconds code is valid, sent as `Config.TimeStep`
+    pub time_step: Option<u32>,
+    ///Number of past and future time steps accepted, sent as `Config.Skew`
+    pub skew: Option<u8>,
+    ///Number of digits in code, sent as `Config.CodeLength`
+    pub code_length: Option<u8>,
+    ///Hash algorithm, sent as `Config.Alg`
+    pub alg: Option<TotpAlgorithm>,
+}
+
+#[derive(Debug, Clone, Copy)]
+///Factor specific parameters.
+pub enum FactorKind<'a> {
+    ///Push factor.
+    Push(PushFactor<'a>),
+    ///TOTP factor.
+    Totp(TotpFactor<'a>),
+}
+
+#[derive(Debug)]
+///Describes new factor of entity, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Factor::list_url`
+pub struct FactorCreate<'a> {
+    ///ID of service
+    pub service_sid: &'a str,
+    ///Unique identity of entity (user), must not contain personally identifiable information
+    pub identity: &'a str,
+    ///Name of factor
+    pub friendly_name: &'a str,
+    ///Factor specific parameters
+    pub kind: FactorKind<'a>,
+}
+
+impl<'a> FactorCreate<'a> {
+    #[inline]
+    ///Creates URL to post factor onto.
+    pub const fn url(&self, host: &Host<'_>) -> ResourceUrl {
+        Factor::list_url(host, self.service_sid, self.identity)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.friendly_name(self.friendly_name);
+        match self.kind {
+            FactorKind::Push(push) => {
+                res.factor_type(&FactorType::Push)
+                   .binding("PublicKey", push.public_key)
+                   .binding("Alg", "ES256")
+                   .config("SdkVersion", push.sdk_version)
+                   .config("AppId", push.app_id)
+                   .config("NotificationPlatform", push.notification_platform);
+                if let Some(token) = push.notification_token {
+                    res.config("NotificationToken", token);
+                }
+            },
+            FactorKind::Totp(totp) => {
+                res.factor_type(&FactorType::Totp);
+                if let Some(secret) = totp.secret {
+                    res.binding("Secret", secret);
+                }
+                let mut buf = str_buf::StrBuf::<10>::new();
+                if let Some(time_step) = totp.time_step {
+                    let _ = write!(buf, "{}", time_step);
+                    res.config("TimeStep", buf.as_str());
+                }
+                if let Some(skew) = totp.skew {
+                    buf.clear();
+                    let _ = write!(buf, "{}", skew);
+                    res.config("Skew", buf.as_str());
+                }
+                if let Some(code_length) = totp.code_length {
+                    buf.clear();
+                    let _ = write!(buf, "{}", code_length);
+                    res.config("CodeLength", buf.as_str());
+                }
+                if let Some(alg) = totp.alg {
+                    res.config("Alg", alg.as_str());
+                }
+            },
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for FactorCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug)]
+///Describes verification of newly created factor, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Factor::url`
+pub struct FactorVerify<'a> {
+    ///ID of service
+    pub service_sid: &'a str,
+    ///Unique identity of entity
+    pub identity: &'a str,
+    ///ID of factor
+    pub sid: &'a str,
+    ///Code generated by TOTP application
+    pub auth_payload: &'a str,
+}
+
+impl<'a> FactorVerify<'a> {
+    #[inline]
+    ///Creates verification of factor `sid` with `auth_payload`.
+    pub const fn new(service_sid: &'a str, identity: &'a str, sid: &'a str, auth_payload: &'a str) -> Self {
+        Self {
+            service_sid,
+            identity,
+            sid,
+            auth_payload,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post verification onto.
+    pub const fn url(&self, host: &Host<'_>) -> ResourceUrl {
+        Factor::url(host, self.service_sid, self.identity, self.sid)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.auth_payload(self.auth_payload);
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for FactorVerify<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Binding of factor, returned only on creation.
+pub struct FactorBinding {
+    ///Shared secret of TOTP factor.
+    pub secret: Option<String>,
+    ///URI to be encoded as QR code for TOTP application.
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+///Configuration of factor.
+pub struct FactorConfig {
+    ///Version of Verify SDK.
+    pub sdk_version: Option<String>,
+    ///ID of mobile application.
+    pub app_id: Option<String>,
+    ///Push platform.
+    pub notification_platform: Option<String>,
+    ///Push token of device.
+    pub notification_token: Option<String>,
+    ///Number of seconds TOTP code is valid.
+    pub time_step: Option<u32>,
+    ///Number of past and future time steps accepted.
+    pub skew: Option<u8>,
+    ///Number of digits in TOTP code.
+    pub code_length: Option<u8>,
+    ///Hash algorithm of TOTP.
+    pub alg: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+///Factor resource.
+pub struct Factor {
+    ///ID of factor
+    pub sid: String,
+    ///ID of account, which owns service.
+    pub account_sid: Option<String>,
+    ///ID of service.
+    pub service_sid: Option<String>,
+    ///ID of entity.
+    pub entity_sid: Option<String>,
+    ///Unique identity of entity.
+    pub identity: Option<String>,
+    ///Name of factor.
+    pub friendly_name: Option<String>,
+    ///Status of factor.
+    pub status: FactorStatus,
+    ///Type of factor.
+    pub factor_type: FactorType,
+    ///Binding of factor, returned only on creation.
+    pub binding: Option<FactorBinding>,
+    ///Configuration of factor.
+    pub config: Option<FactorConfig>,
+    ///Timestamp of when factor is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when factor is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+}
+
+impl Factor {
+    #[inline]
+    ///Creates URL to list or create factors of entity `identity`.
+    pub const fn list_url(host: &Host<'_>, service_sid: &str, identity: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").sid(service_sid).segment("Entities").sid(identity).segment("Factors").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch, verify or delete factor `sid`.
+    pub const fn url(host: &Host<'_>, service_sid: &str, identity: &str, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").sid(service_sid).segment("Entities").sid(identity).segment("Factors").sid(sid).into_url()
+    }
+}
+
+#[derive(Debug)]
+///Describes new challenge of factor, suitable for urlencoded serialization
+///
+///Request is to be posted onto `Challenge::list_url`
+pub struct ChallengeCreate<'a> {
+    ///ID of service
+    pub service_sid: &'a str,
+    ///Unique identity of entity
+    pub identity: &'a str,
+    ///ID of factor to challenge
+    pub factor_sid: &'a str,
+    ///Time when challenge expires, Twilio uses 5 minutes by default
+    pub expiration_date: Option<TwilioDateTime>,
+    ///Message shown to user, sent as `Details.Message`
+    pub message: Option<&'a str>,
+    ///Labeled values shown to user, each sent as `Details.Fields`
+    pub fields: &'a [(&'a str, &'a str)],
+    ///Values not shown to user, sent as `HiddenDetails`
+    pub hidden_details: &'a [(&'a str, &'a str)],
+    ///Code generated by TOTP application, to verify TOTP challenge immediately
+    pub auth_payload: Option<&'a str>,
+}
+
+impl<'a> ChallengeCreate<'a> {
+    #[inline]
+    ///Creates challenge of factor `factor_sid` without details.
+    pub const fn new(service_sid: &'a str, identity: &'a str, factor_sid: &'a str) -> Self {
+        Self {
+            service_sid,
+            identity,
+            factor_sid,
+            expiration_date: None,
+            message: None,
+            fields: &[],
+            hidden_details: &[],
+            auth_payload: None,
+        }
+    }
+
+    #[inline]
+    ///Creates URL to post challenge onto.
+    pub const fn url(&self, host: &Host<'_>) -> ResourceUrl {
+        Challenge::list_url(host, self.service_sid, self.identity)
+    }
+
+    ///Converts to generic TwilioRequest
+    pub fn request(&self) -> TwilioRequest {
+        let mut res = TwilioRequest::new();
+        res.factor_sid(self.factor_sid);
+        if let Some(date) = self.expiration_date {
+            res.expiration_date(date);
+        }
+        if let Some(message) = self.message {
+            res.details("Message", message);
+        }
+        for (label, value) in self.fields.iter() {
+            let field = encoder::json_object([("label", *label), ("value", *value)].iter().copied());
+            res.details("Fields", &field);
+        }
+        if !self.hidden_details.is_empty() {
+            res.hidden_details(self.hidden_details);
+        }
+        if let Some(auth_payload) = self.auth_payload {
+            res.auth_payload(auth_payload);
+        }
+        res
+    }
+}
+
+impl<'a> Into<TwilioRequest> for ChallengeCreate<'a> {
+    #[inline(always)]
+    fn into(self) -> TwilioRequest {
+        self.request()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+///Labeled value of challenge details.
+pub struct ChallengeField {
+    ///Label
+    pub label: String,
+    ///Value
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+///Details of challenge, shown to user.
+pub struct ChallengeDetails {
+    ///Message.
+    pub message: Option<String>,
+    #[serde(default)]
+    ///Labeled values.
+    pub fields: Vec<ChallengeField>,
+    ///Timestamp of challenge.
+    pub date: Option<ResultDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+///Challenge resource.
+pub struct Challenge {
+    ///ID of challenge
+    pub sid: String,
+    ///ID of account, which owns service.
+    pub account_sid: Option<String>,
+    ///ID of service.
+    pub service_sid: Option<String>,
+    ///ID of entity.
+    pub entity_sid: Option<String>,
+    ///Unique identity of entity.
+    pub identity: Option<String>,
+    ///ID of challenged factor.
+    pub factor_sid: Option<String>,
+    ///Type of challenged factor.
+    pub factor_type: Option<FactorType>,
+    ///Status of challenge.
+    pub status: ChallengeStatus,
+    ///Reason of response (`none`, `not_needed` or `not_requested`).
+    pub responded_reason: Option<String>,
+    ///Details shown to user.
+    pub details: Option<ChallengeDetails>,
+    ///Details not shown to user.
+    pub hidden_details: Option<HashMap<String, String>>,
+    ///Timestamp of when challenge is created.
+    pub date_created: Option<ResultDateTime>,
+    ///Timestamp of when challenge is updated.
+    pub date_updated: Option<ResultDateTime>,
+    ///Timestamp of when challenge is responded.
+    pub date_responded: Option<ResultDateTime>,
+    ///Timestamp of when challenge expires.
+    pub expiration_date: Option<ResultDateTime>,
+    ///URL of resource.
+    pub url: Option<String>,
+}
+
+impl Challenge {
+    #[inline]
+    ///Creates URL to list or create challenges of entity `identity`.
+    pub const fn list_url(host: &Host<'_>, service_sid: &str, identity: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").sid(service_sid).segment("Entities").sid(identity).segment("Challenges").into_url()
+    }
+
+    #[inline]
+    ///Creates URL to fetch or update challenge `sid`.
+    pub const fn url(host: &Host<'_>, service_sid: &str, identity: &str, sid: &str) -> ResourceUrl {
+        ProductPath::with_host(host, Product::Verify).segment("Services").sid(service_sid).segment("Entities").sid(identity).segment("Challenges").sid(sid).into_url()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///Type of Verify webhook event.
+///
+///Values, unknown to this library, are preserved as `Unknown`.
+pub enum VerifyEvent {
+    ///Factor is created.
+    FactorCreated,
+    ///Factor is verified.
+    FactorVerified,
+    ///Factor is deleted.
+    FactorDeleted,
+    ///Challenge is approved.
+    ChallengeApproved,
+    ///Challenge is denied.
+    ChallengeDenied,
+    ///Challenge expired.
+    ChallengeExpired,
+    ///Event, unknown to this library.
+    Unknown(String),
+}
+
+impl_str_enum!(VerifyEvent {
+    FactorCreated => "factor.created",
+    FactorVerified => "factor.verified",
+    FactorDeleted => "factor.deleted",
+    ChallengeApproved => "challenge.approved",
+    ChallengeDenied => "challenge.denied",
+    ChallengeExpired => "challenge.expired",
+});
+
+#[derive(Debug, Deserialize)]
+///Payload of Verify webhook, posted on factor and challenge events.
+pub struct VerifyWebhook {
+    ///Unique ID of event, to detect duplicate deliveries.
+    pub uuid: Option<String>,
+    #[serde(rename = "type")]
+    ///Type of event.
+    pub event: VerifyEvent,
+    ///ID of account.
+    pub account_sid: Option<String>,
+    ///ID of service.
+    pub service_sid: Option<String>,
+    ///Unique identity of entity.
+    pub entity_identity: Option<String>,
+    ///ID of factor.
+    pub factor_sid: Option<String>,
+    ///Type of factor.
+    pub factor_type: Option<FactorType>,
+    ///Name of factor.
+    pub factor_friendly_name: Option<String>,
+    ///ID of challenge, for challenge events.
+    pub challenge_sid: Option<String>,
+    ///Status of challenge, for challenge events.
+    pub challenge_status: Option<ChallengeStatus>,
+    ///Reason of response, for challenge events.
+    pub challenge_responded_reason: Option<String>,
+    ///Details of challenge as JSON, for challenge events.
+    pub challenge_details: Option<String>,
+    ///Hidden details of challenge as JSON, for challenge events.
+    pub challenge_hidden_details: Option<String>,
+}
+
+impl VerifyWebhook {
+    #[inline]
+    ///Returns whether challenge is approved.
+    pub fn is_challenge_approved(&self) -> bool {
+        self.event == VerifyEvent::ChallengeApproved
+    }
+}
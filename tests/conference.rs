@@ -0,0 +1,103 @@
+use twilio_data::{Host, Conference, ConferenceList, ConferenceStatus, ConferenceUpdate, Participant, ParticipantList, ParticipantStatus, ParticipantCreate, ParticipantUpdate, Beep, ConferenceEvent, ConferenceStatusCallback};
+
+const ACCOUNT_SID: &str = "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const CONFERENCE_SID: &str = "CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const CALL_SID: &str = "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+#[test]
+fn should_deserialize_conference_and_participants() {
+    const CONFERENCES: &str = r#"{
+        "conferences": [{
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "api_version": "2010-04-01",
+            "date_created": "Fri, 18 Feb 2011 19:26:50 +0000",
+            "date_updated": "Fri, 18 Feb 2011 19:27:33 +0000",
+            "friendly_name": "AHH YEAH",
+            "region": "us1",
+            "sid": "CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "status": "completed",
+            "reason_conference_ended": "last-participant-left",
+            "call_sid_ending_conference": "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Conferences/CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json",
+            "subresource_uris": {
+                "participants": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Conferences/CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Participants.json"
+            }
+        }],
+        "page": 0,
+        "page_size": 1,
+        "next_page_uri": null
+    }"#;
+    const PARTICIPANTS: &str = r#"{
+        "participants": [{
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "call_sid": "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "label": "customer",
+            "conference_sid": "CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "call_sid_to_coach": null,
+            "coaching": false,
+            "date_created": "Fri, 18 Feb 2011 21:07:19 +0000",
+            "date_updated": "Fri, 18 Feb 2011 21:07:19 +0000",
+            "end_conference_on_exit": false,
+            "muted": false,
+            "hold": true,
+            "status": "connected",
+            "start_conference_on_enter": true,
+            "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Conferences/CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Participants/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json"
+        }],
+        "page": 0,
+        "page_size": 50
+    }"#;
+
+    let list: ConferenceList = serde_json::from_str(CONFERENCES).unwrap();
+    assert_eq!(list.conferences[0].status, ConferenceStatus::Completed);
+    assert_eq!(list.conferences[0].friendly_name.as_deref(), Some("AHH YEAH"));
+
+    let list: ParticipantList = serde_json::from_str(PARTICIPANTS).unwrap();
+    let participant = &list.participants[0];
+    assert_eq!(participant.status, Some(ParticipantStatus::Connected));
+    assert!(participant.hold);
+    assert!(!participant.muted);
+    assert!(participant.start_conference_on_enter);
+}
+
+#[test]
+fn should_build_conference_requests() {
+    let host = Host::new();
+
+    assert_eq!(Conference::list_url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Conferences.json");
+
+    let mut update = ConferenceUpdate::end(CONFERENCE_SID);
+    update.announce_url = Some("https://example.com/bye.xml");
+    assert_eq!(update.url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Conferences/CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json");
+    assert_eq!(update.request().as_form(), "Status=completed&AnnounceUrl=https%3A%2F%2Fexample.com%2Fbye.xml");
+
+    let mut participant = ParticipantCreate::new(CONFERENCE_SID, "+15017122661", "+15558675310");
+    participant.early_media = Some(true);
+    participant.beep = Some(Beep::OnEnter);
+    participant.call_sid_to_coach = Some(CALL_SID);
+    assert_eq!(participant.url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Conferences/CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Participants.json");
+    assert_eq!(participant.request().as_form(), "From=%2B15017122661&To=%2B15558675310&EarlyMedia=true&Beep=onEnter&Coaching=true&CallSidToCoach=CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+
+    let mut update = ParticipantUpdate::new(CONFERENCE_SID, CALL_SID);
+    update.hold = Some(true);
+    update.hold_url = Some("https://example.com/hold.xml");
+    update.muted = Some(false);
+    assert_eq!(update.url(&host, ACCOUNT_SID), Participant::url(&host, ACCOUNT_SID, CONFERENCE_SID, CALL_SID));
+    assert_eq!(update.url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Conferences/CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Participants/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json");
+    assert_eq!(update.request().as_form(), "Muted=false&Hold=true&HoldUrl=https%3A%2F%2Fexample.com%2Fhold.xml");
+}
+
+#[test]
+fn should_deserialize_conference_status_callback() {
+    const FORM: &str = "ConferenceSid=CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&FriendlyName=support&AccountSid=ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&SequenceNumber=2&StatusCallbackEvent=participant-hold&CallSid=CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&Muted=false&Hold=true&Coaching=false&EndConferenceOnExit=false&StartConferenceOnEnter=true&Timestamp=Wed%2C+07+Jun+2017+19%3A09%3A45+%2B0000";
+
+    let callback: ConferenceStatusCallback = serde_urlencoded::from_str(FORM).unwrap();
+    assert_eq!(callback.status_callback_event, ConferenceEvent::ParticipantHold);
+    assert_eq!(callback.sequence_number, Some(2));
+    assert_eq!(callback.hold, Some(true));
+    assert_eq!(callback.call_sid.as_deref(), Some(CALL_SID));
+    assert!(callback.timestamp.is_some());
+
+    let callback: ConferenceStatusCallback = serde_urlencoded::from_str("ConferenceSid=CF1&StatusCallbackEvent=conference-record-start").unwrap();
+    assert_eq!(callback.status_callback_event, ConferenceEvent::Unknown("conference-record-start".to_owned()));
+}
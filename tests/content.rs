@@ -0,0 +1,118 @@
+use twilio_data::{Host, Sender};
+use twilio_data::content::{Content, ContentKind, ContentTemplate, ContentTemplateList, ContentAction, ContentMessage, QuickReply, ListItem};
+use twilio_data::content::{TextContent, MediaContent, QuickReplyContent, CallToActionContent, ListPickerContent, CardContent};
+
+#[test]
+fn should_serialize_content() {
+    let replies = [QuickReply { title: "Yes", id: "yes" }, QuickReply { title: "No", id: "no" }];
+    let actions = [
+        ContentAction::Url { title: "Order", url: "https://example.com/{{2}}" },
+        ContentAction::PhoneNumber { title: "Call", phone: "+15551234567" },
+    ];
+    let types = [
+        ContentKind::Text(TextContent { body: "Hi {{1}}" }),
+        ContentKind::QuickReply(QuickReplyContent { body: "Confirm?", actions: &replies }),
+        ContentKind::CallToAction(CallToActionContent { body: "Your order", actions: &actions }),
+    ];
+    let mut content = Content::new("order", "en", &types);
+    content.variables = &[("1", "customer"), ("2", "id")];
+
+    let json = serde_json::to_value(content).unwrap();
+    assert_eq!(json, serde_json::json!({
+        "friendly_name": "order",
+        "language": "en",
+        "variables": { "1": "customer", "2": "id" },
+        "types": {
+            "twilio/text": { "body": "Hi {{1}}" },
+            "twilio/quick-reply": {
+                "body": "Confirm?",
+                "actions": [{ "title": "Yes", "id": "yes" }, { "title": "No", "id": "no" }]
+            },
+            "twilio/call-to-action": {
+                "body": "Your order",
+                "actions": [
+                    { "type": "URL", "title": "Order", "url": "https://example.com/{{2}}" },
+                    { "type": "PHONE_NUMBER", "title": "Call", "phone": "+15551234567" }
+                ]
+            }
+        }
+    }));
+
+    let items = [ListItem { id: "1", item: "Red", description: Some("Bright") }, ListItem { id: "2", item: "Blue", description: None }];
+    let card_actions = [ContentAction::QuickReply(QuickReply { title: "Like", id: "like" })];
+    let types = [
+        ContentKind::Media(MediaContent { body: None, media: &["https://example.com/cat.png"] }),
+        ContentKind::ListPicker(ListPickerContent { body: "Pick color", button: "Colors", items: &items }),
+        ContentKind::Card(CardContent { title: "Cat", subtitle: None, media: &["https://example.com/cat.png"], actions: &card_actions }),
+    ];
+    let json = serde_json::to_value(Content::new("misc", "en", &types)).unwrap();
+    assert_eq!(json["variables"], serde_json::json!({}));
+    assert_eq!(json["types"]["twilio/media"], serde_json::json!({ "media": ["https://example.com/cat.png"] }));
+    assert_eq!(json["types"]["twilio/list-picker"]["items"], serde_json::json!([
+        { "id": "1", "item": "Red", "description": "Bright" },
+        { "id": "2", "item": "Blue" }
+    ]));
+
+    let types = [ContentKind::Text(TextContent { body: "Hi" }), ContentKind::Text(TextContent { body: "Hello" })];
+    assert!(serde_json::to_value(Content::new("duplicate", "en", &types)).is_err());
+    assert_eq!(json["types"]["twilio/card"], serde_json::json!({
+        "title": "Cat",
+        "media": ["https://example.com/cat.png"],
+        "actions": [{ "type": "QUICK_REPLY", "title": "Like", "id": "like" }]
+    }));
+}
+
+#[test]
+fn should_encode_content_variables() {
+    let mut message = ContentMessage::new(Sender::MessagingService("MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"), "whatsapp:+12184758724", "HXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    assert_eq!(message.request().as_form(), "MessagingServiceSid=MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&To=whatsapp%3A%2B12184758724&ContentSid=HXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+
+    message.content_variables = &[("1", "Jo \"the\" \\ \n"), ("2", "ok")];
+    let request = message.request();
+    assert_eq!(request.get_field("Body"), None);
+
+    let variables = request.get_field("ContentVariables").unwrap();
+    assert_eq!(variables, r#"{"1":"Jo \"the\" \\ \n","2":"ok"}"#);
+    let variables: serde_json::Value = serde_json::from_str(&variables).unwrap();
+    assert_eq!(variables["1"], "Jo \"the\" \\ \n");
+    assert_eq!(request.get_field("ContentSid").as_deref(), Some("HXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"));
+}
+
+#[test]
+fn should_deserialize_content_templates() {
+    const CONTENTS: &str = r#"{
+        "contents": [{
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "date_created": "2015-07-30T19:00:00Z",
+            "date_updated": "2015-07-30T19:00:00Z",
+            "friendly_name": "order",
+            "language": "en",
+            "links": {
+                "approval_create": "https://content.twilio.com/v1/Content/HXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/ApprovalRequests/whatsapp"
+            },
+            "sid": "HXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "types": {
+                "twilio/text": { "body": "Hi {{1}}" },
+                "twilio/media": { "body": null, "media": ["https://example.com/cat.png"] }
+            },
+            "url": "https://content.twilio.com/v1/Content/HXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "variables": { "1": "customer" }
+        }],
+        "meta": {
+            "page": 0,
+            "page_size": 50,
+            "key": "contents"
+        }
+    }"#;
+
+    let host = Host::new();
+    assert_eq!(ContentTemplate::list_url(&host), "https://content.twilio.com/v1/Content");
+    assert_eq!(ContentTemplate::url(&host, "HXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"), "https://content.twilio.com/v1/Content/HXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+
+    let list: ContentTemplateList = serde_json::from_str(CONTENTS).unwrap();
+    let template = &list.contents[0];
+    assert!(template.has_kind("twilio/text"));
+    assert!(template.has_kind("twilio/media"));
+    assert!(!template.has_kind("twilio/card"));
+    assert_eq!(template.variables["1"], "customer");
+}
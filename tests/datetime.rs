@@ -0,0 +1,78 @@
+use twilio_data::{TwilioRequest, TwilioDateTime, ScheduleError};
+
+#[test]
+fn should_fmt_iso8601() {
+    assert_eq!(TwilioDateTime::from_unix(0).to_string(), "1970-01-01T00:00:00Z");
+    assert_eq!(TwilioDateTime::from_unix(1_638_304_587).to_string(), "2021-11-30T20:36:27Z");
+    assert_eq!(TwilioDateTime::from_unix(951_782_400).to_string(), "2000-02-29T00:00:00Z");
+    assert_eq!(TwilioDateTime::from_unix(-1).to_string(), "1969-12-31T23:59:59Z");
+
+    assert_eq!(TwilioDateTime::from_utc(2021, 11, 30, 20, 36, 27), Some(TwilioDateTime::from_unix(1_638_304_587)));
+    assert_eq!(TwilioDateTime::from_utc(2021, 2, 29, 0, 0, 0), None);
+    assert_eq!(TwilioDateTime::from_utc(2021, 13, 1, 0, 0, 0), None);
+}
+
+#[test]
+fn should_schedule_message() {
+    let now = TwilioDateTime::from_utc(2021, 11, 30, 20, 0, 0).unwrap();
+
+    let mut req = TwilioRequest::new();
+    req.to("Me").body("Later");
+    assert_eq!(req.schedule(now.add_secs(3_600), now).err(), Some(ScheduleError::MissingMessagingService));
+
+    req.messaging_service_sid("MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    assert_eq!(req.schedule(now.add_secs(60), now).err(), Some(ScheduleError::TooEarly));
+    assert_eq!(req.schedule(now.add_secs(TwilioRequest::SCHEDULE_MAX_SECS + 1), now).err(), Some(ScheduleError::TooLate));
+
+    req.schedule(now.add_secs(TwilioRequest::SCHEDULE_MIN_SECS), now).unwrap();
+    assert_eq!(req.as_form(), "To=Me&Body=Later&MessagingServiceSid=MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&SendAt=2021-11-30T20%3A15%3A00Z&ScheduleType=fixed");
+}
+
+#[test]
+fn should_parse_timestamps() {
+    let expected = TwilioDateTime::from_utc(2010, 8, 18, 20, 1, 40).unwrap();
+
+    assert_eq!("Wed, 18 Aug 2010 20:01:40 +0000".parse::<TwilioDateTime>().unwrap(), expected);
+    assert_eq!("18 Aug 2010 22:01:40 +0200".parse::<TwilioDateTime>().unwrap(), expected);
+    assert_eq!("Wed, 18 Aug 2010 20:01:40 GMT".parse::<TwilioDateTime>().unwrap(), expected);
+    assert_eq!("2010-08-18T20:01:40Z".parse::<TwilioDateTime>().unwrap(), expected);
+    assert_eq!("2010-08-18T15:01:40.123-05:00".parse::<TwilioDateTime>().unwrap(), expected);
+    assert_eq!(expected.rfc2822().to_string(), "Wed, 18 Aug 2010 20:01:40 +0000");
+    assert_eq!(expected.unix(), 1_282_161_700);
+
+    assert!("2010-08-18".parse::<TwilioDateTime>().is_err());
+    assert!("Wed, 32 Aug 2010 20:01:40 +0000".parse::<TwilioDateTime>().is_err());
+    assert!("2010-08-18T20:01:40ü".parse::<TwilioDateTime>().is_err());
+
+    assert!(expected < "2010-08-18T20:01:41Z".parse::<TwilioDateTime>().unwrap());
+}
+
+#[test]
+fn should_serde_timestamp() {
+    let expected = TwilioDateTime::from_utc(2010, 8, 18, 20, 1, 40).unwrap();
+    let json = serde_json::to_string(&expected).unwrap();
+    assert_eq!(json, "\"Wed, 18 Aug 2010 20:01:40 +0000\"");
+    assert_eq!(serde_json::from_str::<TwilioDateTime>(&json).unwrap(), expected);
+}
+
+#[cfg(not(feature = "string-dates"))]
+#[test]
+fn should_deserialize_result_timestamps() {
+    const JSON: &str = r#"{
+        "from": "+12184758724",
+        "to": "+817039769774",
+        "body": "Testing SMS",
+        "sid": "SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "queued",
+        "price": null,
+        "price_unit": "USD",
+        "date_created": "Wed, 18 Aug 2010 20:01:40 +0000",
+        "date_sent": null,
+        "date_updated": "Wed, 18 Aug 2010 20:01:41 +0000"
+    }"#;
+
+    let result: twilio_data::SmsResult = serde_json::from_str(JSON).unwrap();
+    assert_eq!(result.date_created, TwilioDateTime::from_utc(2010, 8, 18, 20, 1, 40));
+    assert_eq!(result.date_sent, None);
+    assert_eq!(result.date_updated.map(|date| date.unix()), Some(1_282_161_701));
+}
@@ -0,0 +1,99 @@
+use core::convert::TryFrom;
+
+use twilio_data::{Host, PhoneNumber};
+use twilio_data::lookup::{LookupRequest, LookupField, LookupResult, IdentityQuery, LineType};
+
+#[test]
+fn should_parse_phone_number() {
+    assert_eq!(PhoneNumber::parse("+14155552345").unwrap().as_str(), "+14155552345");
+    assert!(PhoneNumber::parse("14155552345").is_err());
+    assert!(PhoneNumber::parse("+04155552345").is_err());
+    assert!(PhoneNumber::parse("+1415555234a").is_err());
+    assert!(PhoneNumber::parse("+1234567890123456").is_err());
+    assert!(PhoneNumber::parse("+").is_err());
+}
+
+#[test]
+fn should_build_lookup_request() {
+    let mut lookup = LookupRequest::new("+14159929960");
+    lookup.fields = &[LookupField::LineTypeIntelligence, LookupField::SmsPumpingRisk];
+    assert_eq!(lookup.url(&Host::new()).unwrap(), "https://lookups.twilio.com/v2/PhoneNumbers/+14159929960");
+    assert_eq!(lookup.request().as_form(), "Fields=line_type_intelligence%2Csms_pumping_risk");
+
+    let mut lookup = LookupRequest::new("4159929960");
+    lookup.fields = &[LookupField::IdentityMatch];
+    lookup.country_code = Some("US");
+    assert_eq!(LookupRequest::new("(415) 992-9960").url(&Host::new()).unwrap(), "https://lookups.twilio.com/v2/PhoneNumbers/%28415%29%20992-9960");
+    assert!(LookupRequest::new("").url(&Host::new()).is_err());
+    assert!(LookupRequest::new(&"9".repeat(256)).url(&Host::new()).is_err());
+    lookup.identity = Some(IdentityQuery {
+        first_name: Some("John"),
+        last_name: Some("Doe"),
+        ..Default::default()
+    });
+    assert_eq!(lookup.request().as_form(), "Fields=identity_match&CountryCode=US&FirstName=John&LastName=Doe");
+    assert_eq!(LookupRequest::new("+14159929960").request().as_form(), "");
+}
+
+#[test]
+fn should_deserialize_lookup_result() {
+    const JSON: &str = r#"{
+        "calling_country_code": "1",
+        "country_code": "US",
+        "phone_number": "+14159929960",
+        "national_format": "(415) 992-9960",
+        "valid": true,
+        "validation_errors": [],
+        "caller_name": null,
+        "sim_swap": {
+            "last_sim_swap": {
+                "last_sim_swap_date": "2020-04-27T10:18:50Z",
+                "swapped_period": "PT15282H33M44S",
+                "swapped_in_period": true
+            },
+            "carrier_name": "Vodafone UK",
+            "mobile_country_code": "276",
+            "mobile_network_code": "02",
+            "error_code": null
+        },
+        "call_forwarding": null,
+        "line_status": null,
+        "line_type_intelligence": {
+            "error_code": null,
+            "mobile_country_code": "310",
+            "mobile_network_code": "456",
+            "carrier_name": "verizon",
+            "type": "nonFixedVoip"
+        },
+        "identity_match": null,
+        "sms_pumping_risk": {
+            "carrier_risk_category": "moderate",
+            "number_blocked": false,
+            "number_blocked_date": null,
+            "number_blocked_last_3_months": null,
+            "sms_pumping_risk_score": 64,
+            "error_code": null
+        },
+        "url": "https://lookups.twilio.com/v2/PhoneNumbers/+14159929960"
+    }"#;
+    const INVALID: &str = r#"{
+        "calling_country_code": null,
+        "country_code": null,
+        "phone_number": "+141599299",
+        "national_format": null,
+        "valid": false,
+        "validation_errors": ["TOO_SHORT"],
+        "url": "https://lookups.twilio.com/v2/PhoneNumbers/+141599299"
+    }"#;
+
+    let result: LookupResult = serde_json::from_str(JSON).unwrap();
+    assert_eq!(result.line_type_intelligence.as_ref().unwrap().line_type, Some(LineType::NonFixedVoip));
+    assert_eq!(result.sms_pumping_risk.as_ref().unwrap().sms_pumping_risk_score, Some(64));
+    assert_eq!(result.sim_swap.as_ref().unwrap().last_sim_swap.as_ref().unwrap().swapped_in_period, Some(true));
+    assert!(result.caller_name.is_none());
+    assert_eq!(PhoneNumber::try_from(result).unwrap().as_str(), "+14159929960");
+
+    let result: LookupResult = serde_json::from_str(INVALID).unwrap();
+    assert_eq!(result.validation_errors, ["TOO_SHORT"]);
+    assert!(PhoneNumber::try_from(&result).is_err());
+}
@@ -0,0 +1,130 @@
+use twilio_data::{Host, Sender, Sms, TwilioMethod};
+use twilio_data::messaging::{Service, ServiceList, ServiceCreate, ServiceUpdate, ServicePhoneNumberList, AlphaSender, SenderAdd, PoolSender};
+
+const SERVICE_SID: &str = "MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+#[test]
+fn should_build_service_requests() {
+    let host = Host::new();
+
+    let mut create = ServiceCreate::new("notifications");
+    create.config.inbound_request_url = Some("https://example.com/inbound");
+    create.config.inbound_method = Some(TwilioMethod::GET);
+    create.config.status_callback = Some("https://example.com/status");
+    create.config.sticky_sender = Some(true);
+    create.config.smart_encoding = Some(false);
+    create.config.validity_period = Some(600);
+    create.config.area_code_geomatch = Some(true);
+    assert_eq!(Service::list_url(&host), "https://messaging.twilio.com/v1/Services");
+    assert_eq!(create.request().as_form(), "FriendlyName=notifications&InboundMethod=GET&InboundRequestUrl=https%3A%2F%2Fexample.com%2Finbound&StatusCallback=https%3A%2F%2Fexample.com%2Fstatus&StickySender=true&SmartEncoding=false&ValidityPeriod=600&AreaCodeGeomatch=true");
+
+    let mut update = ServiceUpdate::new(SERVICE_SID);
+    update.friendly_name = Some("alerts");
+    update.config.fallback_url = Some("https://example.com/fallback");
+    assert_eq!(update.url(&host), "https://messaging.twilio.com/v1/Services/MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    assert_eq!(update.request().as_form(), "FriendlyName=alerts&FallbackUrl=https%3A%2F%2Fexample.com%2Ffallback");
+
+    let mut update = ServiceUpdate::new(SERVICE_SID);
+    update.config.inbound_method = Some(TwilioMethod::GET);
+    update.config.fallback_method = Some(TwilioMethod::POST);
+    assert_eq!(update.request().as_form(), "InboundMethod=GET&FallbackMethod=POST");
+}
+
+#[test]
+fn should_build_sender_pool_requests() {
+    let host = Host::new();
+
+    let add = SenderAdd::new(SERVICE_SID, PoolSender::PhoneNumber("PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"));
+    assert_eq!(add.url(&host), "https://messaging.twilio.com/v1/Services/MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/PhoneNumbers");
+    assert_eq!(add.request().as_form(), "PhoneNumberSid=PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+
+    let add = SenderAdd::new(SERVICE_SID, PoolSender::ShortCode("SCXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"));
+    assert_eq!(add.url(&host), "https://messaging.twilio.com/v1/Services/MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/ShortCodes");
+    assert_eq!(add.request().as_form(), "ShortCodeSid=SCXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+
+    let add = SenderAdd::new(SERVICE_SID, PoolSender::AlphaSender("Twilio"));
+    assert_eq!(add.url(&host), "https://messaging.twilio.com/v1/Services/MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/AlphaSenders");
+    assert_eq!(add.request().as_form(), "AlphaSender=Twilio");
+
+    assert_eq!(AlphaSender::url(&host, SERVICE_SID, "AIXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"), "https://messaging.twilio.com/v1/Services/MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/AlphaSenders/AIXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+}
+
+#[test]
+fn should_send_sms_via_messaging_service() {
+    let sms = Sms {
+        from: Sender::MessagingService(SERVICE_SID),
+        to: "+12184758724",
+        body: "Hello",
+    };
+    assert_eq!(sms.request().as_form(), "MessagingServiceSid=MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&To=%2B12184758724&Body=Hello");
+    assert_eq!(sms.to_string(), sms.request().as_form());
+    assert_eq!(serde_urlencoded::to_string(&sms).unwrap(), sms.request().as_form());
+}
+
+#[test]
+fn should_deserialize_services() {
+    const SERVICES: &str = r#"{
+        "services": [{
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "area_code_geomatch": true,
+            "date_created": "2015-07-30T20:12:31Z",
+            "date_updated": "2015-07-30T20:12:33Z",
+            "fallback_method": "POST",
+            "fallback_to_long_code": true,
+            "fallback_url": "https://www.fallback.com",
+            "friendly_name": "My Service!",
+            "inbound_method": "POST",
+            "inbound_request_url": "https://www.sid.com",
+            "links": {
+                "phone_numbers": "https://messaging.twilio.com/v1/Services/MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/PhoneNumbers"
+            },
+            "mms_converter": true,
+            "sid": "MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "smart_encoding": false,
+            "status_callback": "https://www.fallback.com",
+            "sticky_sender": true,
+            "url": "https://messaging.twilio.com/v1/Services/MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "usecase": "marketing",
+            "validity_period": 600
+        }],
+        "meta": {
+            "page": 0,
+            "page_size": 20,
+            "first_page_url": "https://messaging.twilio.com/v1/Services?PageSize=20&Page=0",
+            "previous_page_url": null,
+            "next_page_url": null,
+            "key": "services",
+            "url": "https://messaging.twilio.com/v1/Services?PageSize=20&Page=0"
+        }
+    }"#;
+    const PHONE_NUMBERS: &str = r#"{
+        "phone_numbers": [{
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "capabilities": ["SMS", "MMS"],
+            "country_code": "US",
+            "date_created": "2015-07-30T20:12:31Z",
+            "date_updated": "2015-07-30T20:12:33Z",
+            "phone_number": "+987654321",
+            "service_sid": "MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "sid": "PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "url": "https://messaging.twilio.com/v1/Services/MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/PhoneNumbers/PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+        }],
+        "meta": {
+            "page": 0,
+            "page_size": 20,
+            "key": "phone_numbers"
+        }
+    }"#;
+
+    let list: ServiceList = serde_json::from_str(SERVICES).unwrap();
+    let service = &list.services[0];
+    assert_eq!(service.inbound_method, Some(TwilioMethod::POST));
+    assert!(service.sticky_sender);
+    assert!(!service.smart_encoding);
+    assert_eq!(service.validity_period, Some(600));
+    assert!(service.links.contains_key("phone_numbers"));
+
+    let list: ServicePhoneNumberList = serde_json::from_str(PHONE_NUMBERS).unwrap();
+    assert_eq!(list.phone_numbers[0].capabilities, ["SMS", "MMS"]);
+    assert_eq!(list.meta.key.as_deref(), Some("phone_numbers"));
+}
@@ -0,0 +1,131 @@
+use twilio_data::{Host, TwilioMethod, PhoneNumberType, NumberPattern, LatLong, AvailablePhoneNumberSearch, AvailablePhoneNumberList};
+use twilio_data::{IncomingPhoneNumber, IncomingPhoneNumberList, IncomingPhoneNumberCreate, IncomingPhoneNumberUpdate};
+
+const ACCOUNT_SID: &str = "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const NUMBER_SID: &str = "PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+#[test]
+fn should_build_available_phone_number_search() {
+    assert!(NumberPattern::new("").is_err());
+    assert!(NumberPattern::new("55-5").is_err());
+
+    let mut search = AvailablePhoneNumberSearch::new("US", PhoneNumberType::Local);
+    search.area_code = Some(510);
+    search.contains = Some(NumberPattern::new("STORM*").unwrap());
+    search.sms_enabled = Some(true);
+    search.near_lat_long = Some(LatLong { latitude: 37.840699, longitude: -122.461853 });
+    search.distance = Some(50);
+    search.exclude_all_address_required = Some(true);
+
+    assert_eq!(search.url(&Host::new(), ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/AvailablePhoneNumbers/US/Local.json");
+    assert_eq!(search.request().as_form(), "AreaCode=510&Contains=STORM*&SmsEnabled=true&NearLatLong=37.840699%2C-122.461853&Distance=50&ExcludeAllAddressRequired=true");
+
+    let search = AvailablePhoneNumberSearch::new("GB", PhoneNumberType::TollFree);
+    assert_eq!(search.url(&Host::new(), ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/AvailablePhoneNumbers/GB/TollFree.json");
+}
+
+#[test]
+fn should_deserialize_phone_numbers() {
+    const AVAILABLE: &str = r#"{
+        "available_phone_numbers": [{
+            "address_requirements": "none",
+            "beta": false,
+            "capabilities": {
+                "mms": true,
+                "sms": false,
+                "voice": true
+            },
+            "friendly_name": "(808) 925-1571",
+            "iso_country": "US",
+            "lata": "834",
+            "latitude": "19.720000",
+            "locality": "Hilo",
+            "longitude": "-155.090000",
+            "phone_number": "+18089251571",
+            "postal_code": "96720",
+            "rate_center": "HILO",
+            "region": "HI"
+        }, {
+            "friendly_name": "(800) 123-4567",
+            "phone_number": "+18001234567",
+            "capabilities": {
+                "voice": true,
+                "SMS": true,
+                "MMS": false
+            }
+        }],
+        "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/AvailablePhoneNumbers/US/Local.json"
+    }"#;
+    const INCOMING: &str = r#"{
+        "incoming_phone_numbers": [{
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "address_requirements": "none",
+            "beta": false,
+            "capabilities": {
+                "voice": true,
+                "sms": false,
+                "mms": true,
+                "fax": false
+            },
+            "date_created": "Thu, 30 Jul 2015 23:19:04 +0000",
+            "date_updated": "Thu, 30 Jul 2015 23:19:04 +0000",
+            "friendly_name": "(808) 925-5327",
+            "origin": "origin",
+            "phone_number": "+18089255327",
+            "sid": "PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "sms_fallback_method": "POST",
+            "sms_fallback_url": "",
+            "sms_method": "GET",
+            "sms_url": "https://example.com/sms",
+            "status_callback": "",
+            "status_callback_method": "POST",
+            "voice_fallback_method": "POST",
+            "voice_fallback_url": null,
+            "voice_method": "POST",
+            "voice_url": null,
+            "status": "in-use",
+            "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/IncomingPhoneNumbers/PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json"
+        }],
+        "page": 0,
+        "page_size": 50
+    }"#;
+
+    let list: AvailablePhoneNumberList = serde_json::from_str(AVAILABLE).unwrap();
+    assert_eq!(list.available_phone_numbers[0].phone_number, "+18089251571");
+    assert!(list.available_phone_numbers[0].capabilities.mms);
+    assert!(!list.available_phone_numbers[0].capabilities.sms);
+    assert!(list.available_phone_numbers[1].capabilities.sms);
+
+    let list: IncomingPhoneNumberList = serde_json::from_str(INCOMING).unwrap();
+    let number = &list.incoming_phone_numbers[0];
+    assert_eq!(number.sms_method, Some(TwilioMethod::GET));
+    assert_eq!(number.sms_url.as_deref(), Some("https://example.com/sms"));
+    assert!(number.capabilities.voice);
+    assert!(!number.capabilities.fax);
+}
+
+#[test]
+fn should_build_incoming_phone_number_requests() {
+    let host = Host::new();
+
+    let mut create = IncomingPhoneNumberCreate::new("+18089251571");
+    create.config.sms_url = Some("https://example.com/sms");
+    create.config.voice_url = Some("https://example.com/voice");
+    create.config.voice_method = Some(TwilioMethod::GET);
+    create.config.voice_fallback_url = Some("https://example.com/fallback");
+    assert_eq!(IncomingPhoneNumber::list_url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/IncomingPhoneNumbers.json");
+    assert_eq!(create.url(&host, ACCOUNT_SID), IncomingPhoneNumber::list_url(&host, ACCOUNT_SID));
+    assert_eq!(create.request().as_form(), "PhoneNumber=%2B18089251571&SmsUrl=https%3A%2F%2Fexample.com%2Fsms&VoiceMethod=GET&VoiceUrl=https%3A%2F%2Fexample.com%2Fvoice&VoiceFallbackUrl=https%3A%2F%2Fexample.com%2Ffallback");
+
+    let mut update = IncomingPhoneNumberUpdate::new(NUMBER_SID);
+    update.config.friendly_name = Some("support");
+    update.config.sms_fallback_url = Some("https://example.com/fallback");
+    update.config.sms_fallback_method = Some(TwilioMethod::POST);
+    assert_eq!(update.url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/IncomingPhoneNumbers/PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json");
+    assert_eq!(update.request().as_form(), "FriendlyName=support&SmsFallbackMethod=POST&SmsFallbackUrl=https%3A%2F%2Fexample.com%2Ffallback");
+
+    let mut update = IncomingPhoneNumberUpdate::new(NUMBER_SID);
+    update.config.sms_method = Some(TwilioMethod::GET);
+    update.config.voice_method = Some(TwilioMethod::POST);
+    assert_eq!(update.request().as_form(), "SmsMethod=GET&VoiceMethod=POST");
+}
@@ -1,12 +1,10 @@
-//use twilio_data::TwilioRequest;
-//
-//const ACCOUNT_SID: &str = "<account sid>";
-//const ACCOUNT_TOKEN: &str = "<account token>";
+//use twilio_data::{TwilioRequest, Credentials};
 //
 //#[test]
 //fn should_sms() {
-//    const URL: twilio_data::UrlBuffer = twilio_data::sms_resource_url(ACCOUNT_SID);
-//    let auth = format!("Basic {}", base64::encode(format!("{}:{}", ACCOUNT_SID, ACCOUNT_TOKEN)));
+//    let credentials = Credentials::from_env().expect("Credentials");
+//    let url = twilio_data::sms_resource_url(credentials.account_sid());
+//    let auth = credentials.authorization();
 //
 //    let mut req = TwilioRequest::new();
 //    req.from("+12184758724")
@@ -14,7 +12,7 @@
 //       .body("Testing SMS");
 //
 //    println!("req: {}", req.as_form());
-//    let res = ureq::post(&URL).set("Authorization", auth.as_str())
+//    let res = ureq::post(&url).set("Authorization", auth.as_str())
 //                              .set("Content-Type", "application/x-www-form-urlencoded")
 //                              .set("Accept", "application/json")
 //                              .send_string(req.as_form());
@@ -23,7 +21,7 @@
 //        Ok(res) => {
 //            let res: twilio_data::SmsResult = res.into_json().expect("Get response");
 //            println!("res={:#?}", res);
-//            assert_eq!(res.from, "+12184758724");
+//            assert_eq!(res.from.as_deref(), Some("+12184758724"));
 //            assert_eq!(res.to, "+817039769774");
 //        },
 //        Err(ureq::Error::Status(_status, resp)) => {
@@ -37,8 +35,9 @@
 //
 //#[test]
 //fn should_call() {
-//    const URL: twilio_data::UrlBuffer = twilio_data::call_resource_url(ACCOUNT_SID);
-//    let auth = format!("Basic {}", base64::encode(format!("{}:{}", ACCOUNT_SID, ACCOUNT_TOKEN)));
+//    let credentials = Credentials::from_env().expect("Credentials");
+//    let url = twilio_data::call_resource_url(credentials.account_sid());
+//    let auth = credentials.authorization();
 //
 //    let mut req = TwilioRequest::new();
 //    req.from("+12184758724")
@@ -47,7 +46,7 @@
 //       .twiml("<Response><Say>Ahoy</Say></Response>");
 //
 //    println!("req: {}", req.as_form());
-//    let res = ureq::post(&URL).set("Authorization", auth.as_str())
+//    let res = ureq::post(&url).set("Authorization", auth.as_str())
 //                              .set("Content-Type", TwilioRequest::CONTENT_TYPE)
 //                              .set("Accept", "application/json")
 //                              .send_string(req.as_form());
@@ -0,0 +1,103 @@
+use twilio_data::{Decimal, Currency, Price, CostLedger, SmsResult};
+
+#[test]
+fn should_parse_decimal() {
+    let price: Decimal = "-0.00750".parse().unwrap();
+    assert_eq!(price.units(), -750_000);
+    assert_eq!(price.to_string(), "-0.0075");
+
+    assert_eq!("12".parse::<Decimal>().unwrap().to_string(), "12");
+    assert_eq!("+1.5".parse::<Decimal>().unwrap().to_string(), "1.5");
+    assert_eq!(".25".parse::<Decimal>().unwrap().to_string(), "0.25");
+    assert_eq!("0.123456780".parse::<Decimal>().unwrap().units(), 12_345_678);
+
+    assert!("0.123456789".parse::<Decimal>().is_err());
+    assert!("1.2.3".parse::<Decimal>().is_err());
+    assert!("-".parse::<Decimal>().is_err());
+    assert!("99999999999999".parse::<Decimal>().is_err());
+    assert!("92233720368.9".parse::<Decimal>().is_err());
+    assert_eq!("92233720368.54775807".parse::<Decimal>().unwrap().units(), i64::MAX);
+
+    let max = Decimal::from_units(i64::MAX);
+    let min = Decimal::from_units(i64::MIN);
+    assert_eq!(max.checked_add(Decimal::from_units(1)), None);
+    assert_eq!(min.checked_sub(Decimal::from_units(1)), None);
+    assert_eq!(max + Decimal::from_units(1), max);
+    assert_eq!(min - Decimal::from_units(1), min);
+    assert_eq!(-min, max);
+    assert_eq!(min.abs(), max);
+
+    assert_eq!("usd".parse::<Currency>().unwrap(), Currency::USD);
+    assert!("US".parse::<Currency>().is_err());
+}
+
+#[test]
+fn should_deserialize_and_aggregate_prices() {
+    const JSON: &str = r#"{
+        "from": "+12184758724",
+        "to": "+817039769774",
+        "body": "Testing SMS",
+        "sid": "SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "sent",
+        "price": "-0.00750",
+        "price_unit": "USD",
+        "date_created": "Wed, 18 Aug 2010 20:01:40 +0000",
+        "date_sent": "Wed, 18 Aug 2010 20:01:40 +0000",
+        "date_updated": "Wed, 18 Aug 2010 20:01:41 +0000"
+    }"#;
+    const JSON_NO_PRICE: &str = r#"{
+        "from": "+12184758724",
+        "to": "+817039769774",
+        "body": "Testing SMS",
+        "sid": "SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "queued",
+        "price": null,
+        "price_unit": "USD",
+        "date_created": "Wed, 18 Aug 2010 20:01:40 +0000",
+        "date_sent": null,
+        "date_updated": "Wed, 18 Aug 2010 20:01:41 +0000"
+    }"#;
+
+    let sent: SmsResult = serde_json::from_str(JSON).unwrap();
+    assert_eq!(sent.price, Some(Price { amount: "-0.0075".parse().unwrap(), currency: Currency::USD }));
+    let queued: SmsResult = serde_json::from_str(JSON_NO_PRICE).unwrap();
+    assert_eq!(queued.price, None);
+
+    let mut ledger = CostLedger::new();
+    ledger.add_sms(&sent);
+    ledger.add_sms(&sent);
+    ledger.add_sms(&queued);
+    ledger.add(&Price { amount: "-1.1".parse().unwrap(), currency: Currency::EUR });
+
+    assert_eq!(ledger.total(Currency::USD).to_string(), "-0.015");
+    assert_eq!(ledger.total(Currency::EUR).to_string(), "-1.1");
+    assert_eq!(ledger.total(Currency::GBP), Decimal::ZERO);
+    assert_eq!(ledger.iter().count(), 2);
+}
+
+#[test]
+fn should_reject_malformed_price() {
+    let sms = |price: &str, price_unit: &str| format!(r#"{{
+        "from": "+12184758724",
+        "to": "+817039769774",
+        "body": "Testing SMS",
+        "sid": "SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "sent",
+        "price": {},
+        "price_unit": {},
+        "date_created": "Wed, 18 Aug 2010 20:01:40 +0000",
+        "date_sent": "Wed, 18 Aug 2010 20:01:40 +0000",
+        "date_updated": "Wed, 18 Aug 2010 20:01:41 +0000"
+    }}"#, price, price_unit);
+
+    let result: SmsResult = serde_json::from_str(&sms("\"-0.0075\"", "\"USD\"")).unwrap();
+    assert_eq!(result.price, Some(Price { amount: "-0.0075".parse().unwrap(), currency: Currency::USD }));
+    let result: SmsResult = serde_json::from_str(&sms("2", "\"USD\"")).unwrap();
+    assert_eq!(result.price, Some(Price { amount: "2".parse().unwrap(), currency: Currency::USD }));
+
+    assert!(serde_json::from_str::<SmsResult>(&sms("-0.0075", "\"USD\"")).is_err());
+    assert!(serde_json::from_str::<SmsResult>(&sms("\"abc\"", "\"USD\"")).is_err());
+    assert!(serde_json::from_str::<SmsResult>(&sms("\"-0.0075\"", "\"DOLLARS\"")).is_err());
+    assert!(serde_json::from_str::<SmsResult>(&sms("\"-0.0075\"", "null")).is_err());
+    assert!(serde_json::from_str::<SmsResult>(&sms("1e-10", "\"USD\"")).is_err());
+}
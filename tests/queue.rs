@@ -0,0 +1,79 @@
+use twilio_data::{Host, TwilioMethod, Queue, QueueList, QueueCreate, QueueMember, QueueMemberList, QueueDequeue, QueueResult, EnqueueWaitCallback, EnqueueActionCallback};
+
+const ACCOUNT_SID: &str = "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const QUEUE_SID: &str = "QUXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const CALL_SID: &str = "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+#[test]
+fn should_deserialize_queues_and_members() {
+    const QUEUES: &str = r#"{
+        "queues": [{
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "average_wait_time": 35,
+            "current_size": 2,
+            "date_created": "Tue, 04 Aug 2015 18:39:09 +0000",
+            "date_updated": "Tue, 04 Aug 2015 18:39:09 +0000",
+            "friendly_name": "support",
+            "max_size": 100,
+            "sid": "QUXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Queues/QUXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json"
+        }],
+        "page": 0,
+        "page_size": 50
+    }"#;
+    const MEMBERS: &str = r#"{
+        "queue_members": [{
+            "call_sid": "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "date_enqueued": "Tue, 07 Aug 2012 22:57:41 +0000",
+            "position": 1,
+            "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Queues/QUXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Members/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json",
+            "wait_time": 143,
+            "queue_sid": "QUXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+        }],
+        "page": 0,
+        "page_size": 50
+    }"#;
+
+    let list: QueueList = serde_json::from_str(QUEUES).unwrap();
+    assert_eq!(list.queues[0].current_size, 2);
+    assert_eq!(list.queues[0].average_wait_time, 35);
+
+    let list: QueueMemberList = serde_json::from_str(MEMBERS).unwrap();
+    assert_eq!(list.queue_members[0].position, 1);
+    assert_eq!(list.queue_members[0].wait_time, 143);
+}
+
+#[test]
+fn should_build_queue_requests() {
+    let host = Host::new();
+
+    let mut queue = QueueCreate::new("support");
+    queue.max_size = Some(20);
+    assert_eq!(Queue::list_url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Queues.json");
+    assert_eq!(queue.url(&host, ACCOUNT_SID), Queue::list_url(&host, ACCOUNT_SID));
+    assert_eq!(queue.request().as_form(), "FriendlyName=support&MaxSize=20");
+
+    let dequeue = QueueDequeue::front(QUEUE_SID, "https://example.com/agent.xml");
+    assert_eq!(dequeue.url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Queues/QUXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Members/Front.json");
+    assert_eq!(dequeue.request().as_form(), "Url=https%3A%2F%2Fexample.com%2Fagent.xml");
+
+    let dequeue = QueueDequeue {
+        call_sid: CALL_SID,
+        method: Some(TwilioMethod::GET),
+        ..dequeue
+    };
+    assert_eq!(dequeue.url(&host, ACCOUNT_SID), QueueMember::url(&host, ACCOUNT_SID, QUEUE_SID, CALL_SID));
+    assert_eq!(dequeue.request().as_form(), "Method=GET&Url=https%3A%2F%2Fexample.com%2Fagent.xml");
+}
+
+#[test]
+fn should_deserialize_enqueue_callbacks() {
+    let wait: EnqueueWaitCallback = serde_urlencoded::from_str("CallSid=CA1&QueueSid=QU1&QueuePosition=3&QueueTime=42&AvgQueueTime=30&CurrentQueueSize=5&MaxQueueSize=100").unwrap();
+    assert_eq!(wait.queue_position, Some(3));
+    assert_eq!(wait.queue_time, Some(42));
+    assert_eq!(wait.current_queue_size, Some(5));
+
+    let action: EnqueueActionCallback = serde_urlencoded::from_str("CallSid=CA1&QueueSid=QU1&QueueResult=bridged&QueueTime=42").unwrap();
+    assert_eq!(action.queue_result, QueueResult::Bridged);
+    assert_eq!(action.queue_time, Some(42));
+}
@@ -0,0 +1,53 @@
+use twilio_data::{TwilioRequest, Sms, RateLimiter, SenderThroughput, SendSlot, ValidityExceeded};
+
+use core::time::Duration;
+
+#[test]
+fn should_schedule_per_sender() {
+    let mut limiter = RateLimiter::new(SenderThroughput::LongCode);
+    limiter.set_throughput("+18005550100", SenderThroughput::TollFree);
+    let now = Duration::from_secs(100);
+
+    let sms = Sms {
+        from: "+12184758724".into(),
+        to: "+817039769774",
+        body: "Testing SMS",
+    };
+
+    assert_eq!(limiter.schedule_sms(&sms, now, None).unwrap(), SendSlot { at: now, wait: Duration::from_secs(0) });
+    assert_eq!(limiter.schedule_sms(&sms, now, None).unwrap(), SendSlot { at: now + Duration::from_secs(1), wait: Duration::from_secs(1) });
+    assert_eq!(limiter.wait_time(sms.from.as_str(), now), Duration::from_secs(2));
+    assert_eq!(limiter.wait_time(sms.from.as_str(), now + Duration::from_secs(5)), Duration::from_secs(0));
+
+    limiter.schedule("+18005550100", now, None).unwrap();
+    assert_eq!(limiter.wait_time("+18005550100", now), Duration::from_secs(1) / 3);
+}
+
+#[test]
+fn should_refuse_when_validity_exceeded() {
+    let mut limiter = RateLimiter::new(SenderThroughput::LongCode);
+    let now = Duration::from_secs(0);
+
+    for _ in 0..3 {
+        limiter.schedule("+12184758724", now, Some(2)).unwrap();
+    }
+    let error = limiter.schedule("+12184758724", now, Some(2)).unwrap_err();
+    assert_eq!(error, ValidityExceeded { wait: Duration::from_secs(3), validity_period: 2 });
+    //Slot is not reserved when message is refused
+    assert_eq!(limiter.wait_time("+12184758724", now), Duration::from_secs(3));
+}
+
+#[test]
+fn should_schedule_request_by_messaging_service() {
+    let mut limiter = RateLimiter::new(SenderThroughput::ShortCode);
+    let now = Duration::from_secs(0);
+
+    let mut req = TwilioRequest::new();
+    req.from("+12184758724").messaging_service_sid("MG00000000000000000000000000000000").validity_period(1);
+
+    limiter.schedule_request(&req, now).unwrap().unwrap();
+    assert_eq!(limiter.wait_time("MG00000000000000000000000000000000", now), Duration::from_millis(10));
+    assert_eq!(limiter.wait_time("+12184758724", now), Duration::from_secs(0));
+
+    assert!(limiter.schedule_request(&TwilioRequest::new(), now).is_none());
+}
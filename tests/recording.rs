@@ -0,0 +1,114 @@
+use twilio_data::{Host, Recording, RecordingList, RecordingStatus, RecordingSource, RecordingFormat, RecordingStatusCallback, recording_media_url};
+use twilio_data::{CallRecording, CallRecordingUpdate, PauseBehavior, RecordingChannels, RecordingTrack, RecordingStatusCallbackEvent, CURRENT_RECORDING};
+
+const ACCOUNT_SID: &str = "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const RECORDING_SID: &str = "REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const CALL_SID: &str = "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+#[test]
+fn should_deserialize_recording_list() {
+    const JSON: &str = r#"{
+        "end": 0,
+        "first_page_uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings.json?PageSize=1&Page=0",
+        "next_page_uri": null,
+        "page": 0,
+        "page_size": 1,
+        "previous_page_uri": null,
+        "recordings": [{
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "api_version": "2010-04-01",
+            "call_sid": "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "conference_sid": null,
+            "channels": 2,
+            "date_created": "Fri, 14 Oct 2016 21:56:34 +0000",
+            "date_updated": "Fri, 14 Oct 2016 21:56:38 +0000",
+            "start_time": "Fri, 14 Oct 2016 21:56:34 +0000",
+            "price": "-0.0025",
+            "price_unit": "USD",
+            "duration": "4",
+            "sid": "REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "source": "StartConferenceRecordingAPI",
+            "status": "completed",
+            "error_code": null,
+            "encryption_details": {
+                "encryption_public_key_sid": "CRXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+                "encryption_cek": "OV4h6zrsxMIW7h0Zfqwfn6TI2GCNl54KALlnMGUyMzg4NmU1NjMyMmNmOWMwZTRhYzY1NzkyMjgzMzY="
+            },
+            "track": "both",
+            "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings/REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json",
+            "media_url": "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings/REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+        }],
+        "start": 0,
+        "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings.json?PageSize=1&Page=0"
+    }"#;
+
+    let list: RecordingList = serde_json::from_str(JSON).unwrap();
+    assert_eq!(list.page.page_size, Some(1));
+    assert_eq!(list.page.next_page_uri, None);
+
+    let recording = &list.recordings[0];
+    assert_eq!(recording.status, RecordingStatus::Completed);
+    assert_eq!(recording.source, Some(RecordingSource::StartConferenceRecordingApi));
+    assert_eq!(recording.duration, Some(4));
+    assert_eq!(recording.channels, Some(2));
+    assert_eq!(recording.encryption_details.as_ref().unwrap().public_key_sid.as_deref(), Some("CRXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"));
+}
+
+#[test]
+fn should_build_recording_urls() {
+    let host = Host::new();
+
+    assert_eq!(Recording::list_url(&host, ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings.json");
+    assert_eq!(Recording::url(&host, ACCOUNT_SID, RECORDING_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings/REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json");
+    assert_eq!(recording_media_url(&host, ACCOUNT_SID, RECORDING_SID, RecordingFormat::Mp3, None), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings/REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.mp3");
+    assert_eq!(recording_media_url(&host, ACCOUNT_SID, RECORDING_SID, RecordingFormat::Wav, Some(RecordingChannels::Dual)), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings/REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.wav?RequestedChannels=2");
+    assert_eq!(recording_media_url(&host, ACCOUNT_SID, RECORDING_SID, RecordingFormat::Wav, Some(RecordingChannels::Mono)), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings/REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.wav?RequestedChannels=1");
+}
+
+#[test]
+fn should_deserialize_recording_status_callback() {
+    const FORM: &str = "AccountSid=ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&CallSid=CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&RecordingSid=REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&RecordingStatus=completed&RecordingDuration=4&RecordingChannels=1&RecordingSource=RecordVerb&RecordingStartTime=Fri%2C+14+Oct+2016+21%3A56%3A34+%2B0000";
+
+    let callback: RecordingStatusCallback = serde_urlencoded::from_str(FORM).unwrap();
+    assert_eq!(callback.recording_status, RecordingStatus::Completed);
+    assert_eq!(callback.recording_duration, Some(4));
+    assert_eq!(callback.recording_source, Some(RecordingSource::RecordVerb));
+    assert!(callback.recording_start_time.is_some());
+    assert_eq!(callback.error_code, None);
+
+    let callback: RecordingStatusCallback = serde_urlencoded::from_str("RecordingSid=REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&RecordingStatus=failed&ErrorCode=13617").unwrap();
+    assert_eq!(callback.error_code, Some(13617));
+}
+
+#[test]
+fn should_start_call_recording() {
+    let mut recording = CallRecording::new(CALL_SID);
+    recording.channels = Some(RecordingChannels::Dual);
+    recording.track = Some(RecordingTrack::Both);
+    recording.status_callback = Some("https://example.com/recording");
+    recording.status_callback_events = &[RecordingStatusCallbackEvent::InProgress, RecordingStatusCallbackEvent::Completed];
+
+    assert_eq!(recording.url(&Host::new(), ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings.json");
+    assert_eq!(recording.request().as_form(), "RecordingChannels=dual&RecordingTrack=both&RecordingStatusCallback=https%3A%2F%2Fexample.com%2Frecording&RecordingStatusCallbackEvent=in-progress+completed");
+    assert_eq!(CallRecording::new(CALL_SID).request().as_form(), "");
+
+    let mut request = twilio_data::TwilioRequest::new();
+    request.recording_status_callback_event(&[
+        RecordingStatusCallbackEvent::InProgress, RecordingStatusCallbackEvent::Completed, RecordingStatusCallbackEvent::Absent,
+        RecordingStatusCallbackEvent::Completed, RecordingStatusCallbackEvent::InProgress, RecordingStatusCallbackEvent::Absent,
+    ]);
+    assert_eq!(request.as_form(), "RecordingStatusCallbackEvent=in-progress+completed+absent");
+}
+
+#[test]
+fn should_pause_resume_and_stop_call_recording() {
+    let pause = CallRecordingUpdate::pause(CALL_SID, CURRENT_RECORDING, PauseBehavior::Skip);
+    assert_eq!(pause.url(&Host::new(), ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings/Twilio.CURRENT.json");
+    assert_eq!(pause.request().as_form(), "Status=paused&PauseBehavior=skip");
+
+    let resume = CallRecordingUpdate::resume(CALL_SID, RECORDING_SID);
+    assert_eq!(resume.url(&Host::new(), ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings/REXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json");
+    assert_eq!(resume.request().as_form(), "Status=in-progress");
+
+    assert_eq!(CallRecordingUpdate::stop(CALL_SID, RECORDING_SID).request().as_form(), "Status=stopped");
+}
@@ -0,0 +1,163 @@
+use twilio_data::{SmsResult, SmsStatus, MessageDirection};
+
+#[test]
+fn should_deserialize_message_resource() {
+    const JSON: &str = r#"{
+        "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "api_version": "2010-04-01",
+        "body": "Hello! 👍",
+        "date_created": "Thu, 24 Aug 2023 05:01:45 +0000",
+        "date_sent": "Thu, 24 Aug 2023 05:01:45 +0000",
+        "date_updated": "Thu, 24 Aug 2023 05:01:45 +0000",
+        "direction": "outbound-api",
+        "error_code": null,
+        "error_message": null,
+        "from": "+14155552345",
+        "num_media": "0",
+        "num_segments": "1",
+        "price": null,
+        "price_unit": null,
+        "messaging_service_sid": "MGXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "sid": "SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "queued",
+        "subresource_uris": {
+            "media": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages/SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Media.json"
+        },
+        "to": "+14155552345",
+        "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages/SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json"
+    }"#;
+
+    let result: SmsResult = serde_json::from_str(JSON).unwrap();
+    assert_eq!(result.account_sid.as_deref(), Some("ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"));
+    assert_eq!(result.status, SmsStatus::Queued);
+    assert!(matches!(result.direction, Some(MessageDirection::OutboundApi)));
+    assert_eq!(result.num_media, Some(0));
+    assert_eq!(result.num_segments, Some(1));
+    assert_eq!(result.error_code, None);
+    assert!(result.price.is_none());
+    assert!(result.subresource_uris.unwrap().contains_key("media"));
+
+    const MINIMAL_JSON: &str = r#"{
+        "sid": "SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "undelivered",
+        "from": null,
+        "error_code": 30003,
+        "error_message": "Unreachable destination handset",
+        "num_segments": ""
+    }"#;
+    let result: SmsResult = serde_json::from_str(MINIMAL_JSON).unwrap();
+    assert_eq!(result.from, None);
+    assert_eq!(result.error_code, Some(30003));
+    assert_eq!(result.num_media, None);
+    assert_eq!(result.num_segments, None);
+    assert_eq!(result.body, "");
+}
+
+#[test]
+fn should_deserialize_call_resource() {
+    use twilio_data::{CallResult, AnsweredBy};
+
+    const JSON: &str = r#"{
+        "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "annotation": null,
+        "answered_by": "machine_end_beep",
+        "api_version": "2010-04-01",
+        "caller_name": null,
+        "date_created": "Tue, 31 Aug 2010 20:36:28 +0000",
+        "date_updated": "Tue, 31 Aug 2010 20:36:44 +0000",
+        "direction": "outbound-api",
+        "duration": "15",
+        "end_time": "Tue, 31 Aug 2010 20:36:44 +0000",
+        "forwarded_from": "+141586753093",
+        "from": "+15017122661",
+        "from_formatted": "(501) 712-2661",
+        "group_sid": null,
+        "parent_call_sid": null,
+        "phone_number_sid": "PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "price": "-0.03000",
+        "price_unit": "USD",
+        "sid": "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "start_time": "Tue, 31 Aug 2010 20:36:29 +0000",
+        "status": "completed",
+        "subresource_uris": {
+            "notifications": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Notifications.json",
+            "recordings": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings.json"
+        },
+        "to": "+14155552345",
+        "to_formatted": "(415) 555-2345",
+        "trunk_sid": null,
+        "uri": "/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json",
+        "queue_time": "1000"
+    }"#;
+
+    let result: CallResult = serde_json::from_str(JSON).unwrap();
+    assert_eq!(result.duration, Some(15));
+    assert_eq!(result.queue_time, Some(1000));
+    assert_eq!(result.answered_by, Some(AnsweredBy::MachineEndBeep));
+    assert_eq!(result.phone_number_sid.as_deref(), Some("PNXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"));
+    assert_eq!(result.price.unwrap().amount.to_string(), "-0.03");
+    assert_eq!(result.subresource_uris.unwrap().len(), 2);
+
+    const MINIMAL_JSON: &str = r#"{
+        "sid": "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "queued",
+        "duration": null
+    }"#;
+    let result: CallResult = serde_json::from_str(MINIMAL_JSON).unwrap();
+    assert_eq!(result.duration, None);
+    assert_eq!(result.queue_time, None);
+    assert!(result.answered_by.is_none());
+
+    const EMPTY_DURATION_JSON: &str = r#"{
+        "sid": "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "ringing",
+        "duration": "",
+        "queue_time": ""
+    }"#;
+    let result: CallResult = serde_json::from_str(EMPTY_DURATION_JSON).unwrap();
+    assert_eq!(result.duration, None);
+    assert_eq!(result.queue_time, None);
+
+    const NULL_QUEUE_TIME_JSON: &str = r#"{
+        "sid": "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "status": "queued",
+        "queue_time": null
+    }"#;
+    let result: CallResult = serde_json::from_str(NULL_QUEUE_TIME_JSON).unwrap();
+    assert_eq!(result.queue_time, None);
+}
+
+#[test]
+fn should_preserve_unknown_status() {
+    use twilio_data::{CallStatus, CallDirection, AnsweredBy};
+    use std::collections::HashMap;
+
+    let status: SmsStatus = serde_json::from_str("\"partially_delivered\"").unwrap();
+    assert_eq!(status, SmsStatus::PartiallyDelivered);
+    assert_eq!(status.to_string(), "partially_delivered");
+
+    let status: SmsStatus = serde_json::from_str("\"teleported\"").unwrap();
+    assert_eq!(status, SmsStatus::Unknown("teleported".to_owned()));
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"teleported\"");
+
+    let status: CallStatus = "in-progress".parse().unwrap();
+    assert_eq!(status, CallStatus::InProgress);
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"in-progress\"");
+
+    let direction: CallDirection = serde_json::from_str("\"outbound-dial\"").unwrap();
+    assert_eq!(direction, CallDirection::OutboundDial);
+    assert_eq!("trunking-sideways".parse::<CallDirection>().unwrap().as_str(), "trunking-sideways");
+    assert_eq!("outbound-reply".parse::<MessageDirection>().unwrap(), MessageDirection::OutboundReply);
+
+    let answered_by: AnsweredBy = serde_json::from_str("\"unknown\"").unwrap();
+    assert_eq!(answered_by, AnsweredBy::Undetermined);
+    let answered_by: AnsweredBy = serde_json::from_str("\"machine_end_music\"").unwrap();
+    assert_eq!(answered_by, AnsweredBy::Unknown("machine_end_music".to_owned()));
+
+    let mut counts = HashMap::new();
+    for status in ["queued", "sent", "queued", "teleported"].iter() {
+        *counts.entry(status.parse::<SmsStatus>().unwrap()).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&SmsStatus::Queued], 2);
+    assert_eq!(counts[&SmsStatus::Unknown("teleported".to_owned())], 1);
+}
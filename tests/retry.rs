@@ -0,0 +1,73 @@
+use twilio_data::{TwilioRequest, TwilioError, RetryPolicy, RetryDecision};
+
+use core::time::Duration;
+
+const NO_HEADERS: [(&str, &str); 0] = [];
+
+#[test]
+fn should_backoff_exponentially() {
+    let policy = RetryPolicy::new().jitter(false).base_delay(Duration::from_secs(1)).max_delay(Duration::from_secs(5));
+
+    assert_eq!(policy.backoff(0), Duration::from_secs(1));
+    assert_eq!(policy.backoff(1), Duration::from_secs(2));
+    assert_eq!(policy.backoff(2), Duration::from_secs(4));
+    assert_eq!(policy.backoff(3), Duration::from_secs(5));
+    assert_eq!(policy.backoff(64), Duration::from_secs(5));
+
+    let policy = policy.jitter(true).seed(42);
+    for attempt in 0..10 {
+        let delay = policy.backoff(attempt);
+        let max = policy.jitter(false).backoff(attempt);
+        assert!(delay <= max);
+        assert!(delay >= max / 2);
+    }
+}
+
+#[test]
+fn should_jitter_differently_for_each_policy() {
+    let delays = |policy: RetryPolicy| (0..8).map(|attempt| policy.backoff(attempt)).collect::<Vec<_>>();
+
+    assert_ne!(delays(RetryPolicy::new()), delays(RetryPolicy::new()));
+    assert_eq!(delays(RetryPolicy::new().seed(42)), delays(RetryPolicy::new().seed(42)));
+}
+
+#[test]
+fn should_retry_throttled_request() {
+    let policy = RetryPolicy::new().jitter(false).base_delay(Duration::from_secs(1)).max_retries(2);
+    let req = TwilioRequest::new();
+
+    assert_eq!(policy.check(0, &req, 429, NO_HEADERS, None), RetryDecision::Retry(Duration::from_secs(1)));
+    assert_eq!(policy.check(1, &req, 429, [("retry-after", " 7 ")], None), RetryDecision::Retry(Duration::from_secs(7)));
+    assert_eq!(policy.check(2, &req, 429, NO_HEADERS, None), RetryDecision::Stop);
+
+    let error = TwilioError {
+        code: 20429,
+        message: "Too Many Requests".to_owned(),
+        status: 400,
+    };
+    assert_eq!(policy.check(0, &req, 400, NO_HEADERS, Some(&error)), RetryDecision::Retry(Duration::from_secs(1)));
+
+    let error = TwilioError {
+        code: 21211,
+        message: "Invalid 'To' Phone Number".to_owned(),
+        status: 400,
+    };
+    assert_eq!(policy.check(0, &req, 400, NO_HEADERS, Some(&error)), RetryDecision::Stop);
+}
+
+#[test]
+fn should_retry_server_error_only_with_idempotency_token() {
+    let policy = RetryPolicy::new().jitter(false).base_delay(Duration::from_secs(1));
+    let mut req = TwilioRequest::new();
+    req.to("Me");
+
+    assert_eq!(policy.check(0, &req, 500, NO_HEADERS, None), RetryDecision::Stop);
+    assert_eq!(policy.check(0, &req, 503, NO_HEADERS, None), RetryDecision::Retry(Duration::from_secs(1)));
+    assert_eq!(policy.check_transport_error(0, &req), RetryDecision::Stop);
+
+    req.idempotency_token("token");
+    assert_eq!(req.get_idempotency_token(), Some("token"));
+    assert_eq!(req.as_form(), "To=Me");
+    assert_eq!(policy.check(0, &req, 500, NO_HEADERS, None), RetryDecision::Retry(Duration::from_secs(1)));
+    assert_eq!(policy.check_transport_error(1, &req), RetryDecision::Retry(Duration::from_secs(2)));
+}
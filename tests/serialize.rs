@@ -1,4 +1,4 @@
-use twilio_data::{TwilioRequest, Mms, Sms, Call, CallInstruction};
+use twilio_data::{TwilioRequest, Mms, Sms, Call, CallInstruction, CallUpdate, CallUpdateStatus, MessageUpdate, MessageNotScheduled, SmsStatus, Host};
 
 #[test]
 fn should_serde_request() {
@@ -17,7 +17,7 @@ fn should_serde_request() {
 fn should_fmt_sms() {
     const EXPECTED: &str = "From=LOLKA&To=Me&Body=My+cute+text";
     let sms = Sms {
-        from: "LOLKA",
+        from: "LOLKA".into(),
         to: "Me",
         body: "My cute text",
     };
@@ -33,7 +33,7 @@ fn should_fmt_mms() {
     const EXPECTED: &str = "From=LOLKA&To=Me&Body=My+cute+text&MediaUrl=test.png";
     let mms = Mms {
         sms: Sms {
-            from: "LOLKA",
+            from: "LOLKA".into(),
             to: "Me",
             body: "My cute text",
         },
@@ -75,3 +75,40 @@ fn should_fmt_call_with_xml() {
     assert_eq!(serde_urlencoded::to_string(call).unwrap(), EXPECTED);
     assert_eq!(raw.as_form(), EXPECTED);
 }
+
+#[test]
+fn should_fmt_call_update() {
+    const EXPECTED: &str = "Twiml=%3CResponse%3E%3CHangup%2F%3E%3C%2FResponse%3E&TimeLimit=60";
+    let mut update = CallUpdate::new("CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    update.instruction = Some(CallInstruction::Twiml("<Response><Hangup/></Response>"));
+    update.time_limit = Some(60);
+
+    assert_eq!(update.request().as_form(), EXPECTED);
+    assert_eq!(update.url(&Host::new(), "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json");
+
+    const EXPECTED_HANGUP: &str = "Status=completed&StatusCallback=https%3A%2F%2Fdomain.com%2Fstatus";
+    let update = CallUpdate {
+        status: Some(CallUpdateStatus::Completed),
+        status_callback: Some("https://domain.com/status"),
+        ..CallUpdate::new("CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")
+    };
+    let raw: TwilioRequest = update.into();
+    assert_eq!(raw.as_form(), EXPECTED_HANGUP);
+}
+
+#[test]
+fn should_fmt_message_update() {
+    const SID: &str = "SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+    let status: SmsStatus = serde_json::from_str("\"scheduled\"").unwrap();
+    let cancel = MessageUpdate::cancel(SID, Some(&status)).unwrap();
+    assert_eq!(cancel.request().as_form(), "Status=canceled");
+    assert_eq!(cancel.url(&Host::new(), "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages/SMXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json");
+
+    let status: SmsStatus = serde_json::from_str("\"partially_delivered\"").unwrap();
+    assert_eq!(status, SmsStatus::PartiallyDelivered);
+    assert_eq!(MessageUpdate::cancel(SID, Some(&status)).unwrap_err(), MessageNotScheduled);
+
+    let redact: TwilioRequest = MessageUpdate::redact(SID).into();
+    assert_eq!(redact.as_form(), "Body=");
+}
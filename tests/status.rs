@@ -0,0 +1,73 @@
+use twilio_data::{SmsStatus, CallStatus, Lifecycle, StatusTracker, SmsStatusCallback, CallStatusCallback};
+
+use core::cmp::Ordering;
+
+#[test]
+fn should_order_sms_lifecycle() {
+    assert!(SmsStatus::Queued.can_transition_to(&SmsStatus::Delivered));
+    assert!(SmsStatus::Delivered.can_transition_to(&SmsStatus::Read));
+    assert!(!SmsStatus::Delivered.can_transition_to(&SmsStatus::Sent));
+    assert!(!SmsStatus::Queued.can_transition_to(&SmsStatus::Canceled));
+
+    assert!(SmsStatus::Sending < SmsStatus::Undelivered);
+    assert!(SmsStatus::Read > SmsStatus::Accepted);
+    assert_eq!(SmsStatus::Delivered.partial_cmp(&SmsStatus::Undelivered), None);
+    assert_eq!(SmsStatus::Received.partial_cmp(&SmsStatus::Sent), None);
+    assert_eq!(SmsStatus::Sent.partial_cmp(&SmsStatus::Sent), Some(Ordering::Equal));
+
+    assert!(SmsStatus::Undelivered.is_terminal());
+    assert!(!SmsStatus::Undelivered.is_success());
+    assert!(SmsStatus::Delivered.is_success());
+    assert!(!SmsStatus::Sent.is_terminal());
+    assert!(!SmsStatus::Delivered.is_terminal());
+
+    let all = [
+        SmsStatus::Accepted, SmsStatus::Scheduled, SmsStatus::Queued, SmsStatus::Sending, SmsStatus::Sent,
+        SmsStatus::Delivered, SmsStatus::Undelivered, SmsStatus::PartiallyDelivered, SmsStatus::Failed,
+        SmsStatus::Canceled, SmsStatus::Receiving, SmsStatus::Received, SmsStatus::Read,
+    ];
+    for status in all.iter() {
+        let has_successor = all.iter().any(|next| status.can_transition_to(next));
+        assert_eq!(status.is_terminal(), !has_successor, "{}", status);
+    }
+}
+
+#[test]
+fn should_order_call_lifecycle() {
+    assert!(CallStatus::Queued < CallStatus::Completed);
+    assert!(CallStatus::Ringing < CallStatus::NoAnswer);
+    assert_eq!(CallStatus::Busy.partial_cmp(&CallStatus::Completed), None);
+    assert!(CallStatus::Busy.is_terminal());
+    assert!(CallStatus::Completed.is_success());
+    assert!(!CallStatus::InProgress.is_terminal());
+}
+
+#[test]
+fn should_track_out_of_order_callbacks() {
+    let mut tracker = StatusTracker::new();
+
+    let sent: SmsStatusCallback = serde_urlencoded::from_str("MessageSid=SM1&MessageStatus=sent").unwrap();
+    let delivered: SmsStatusCallback = serde_urlencoded::from_str("MessageSid=SM1&MessageStatus=delivered&To=%2B14155552345").unwrap();
+    assert!(tracker.track_sms(&delivered));
+    assert!(!tracker.track_sms(&sent));
+    assert_eq!(tracker.get("SM1"), Some(&SmsStatus::Delivered));
+    assert_eq!(delivered.error_code, None);
+
+    let undelivered: SmsStatusCallback = serde_urlencoded::from_str("MessageSid=SM2&MessageStatus=undelivered&ErrorCode=30003").unwrap();
+    assert_eq!(undelivered.error_code, Some(30003));
+    let sent: SmsStatusCallback = serde_urlencoded::from_str("MessageSid=SM2&MessageStatus=sent&ErrorCode=").unwrap();
+    assert_eq!(sent.error_code, None);
+
+    let mut tracker = StatusTracker::new();
+    let ringing: CallStatusCallback = serde_urlencoded::from_str("CallSid=CA1&CallStatus=ringing&SequenceNumber=1").unwrap();
+    let completed: CallStatusCallback = serde_urlencoded::from_str("CallSid=CA1&CallStatus=completed&SequenceNumber=3&CallDuration=15").unwrap();
+    let answered: CallStatusCallback = serde_urlencoded::from_str("CallSid=CA1&CallStatus=in-progress&SequenceNumber=2").unwrap();
+    assert!(tracker.track_call(&ringing));
+    assert!(tracker.track_call(&completed));
+    assert!(!tracker.track_call(&answered));
+    assert_eq!(tracker.get("CA1"), Some(&CallStatus::Completed));
+    assert_eq!(completed.call_duration, Some(15));
+
+    assert_eq!(tracker.remove("CA1"), Some(CallStatus::Completed));
+    assert!(tracker.is_empty());
+}
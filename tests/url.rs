@@ -0,0 +1,87 @@
+use twilio_data::{Host, ResourcePath};
+
+const ACCOUNT_SID: &str = "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+#[test]
+fn should_build_global_url() {
+    const URL: twilio_data::UrlBuffer = twilio_data::sms_resource_url(ACCOUNT_SID);
+    assert_eq!(URL, "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages.json");
+    assert_eq!(twilio_data::get_call_base(ACCOUNT_SID), "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls");
+}
+
+#[test]
+fn should_build_regional_url() {
+    const HOST: Host<'static> = Host::new().region("ie1").edge("dublin");
+    const URL: twilio_data::UrlBuffer = HOST.sms_resource_url(ACCOUNT_SID);
+    assert_eq!(URL, "https://api.dublin.ie1.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages.json");
+
+    let host = Host::new().region("au1");
+    assert_eq!(host.get_sms_base(ACCOUNT_SID), "https://api.au1.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Messages");
+
+    let host = Host::new().edge("singapore");
+    assert_eq!(host.call_resource_url(ACCOUNT_SID), "https://api.singapore.us1.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls.json");
+}
+
+#[test]
+#[should_panic(expected = "Edge is too long")]
+fn should_reject_too_long_edge() {
+    let _ = Host::new().region("ie1").edge("a-very-long-edge-location-name");
+}
+
+#[test]
+fn should_build_custom_url() {
+    let host = Host::custom("http://127.0.0.1:8080");
+    assert_eq!(host.get_call_base(ACCOUNT_SID), "http://127.0.0.1:8080/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls");
+}
+
+#[test]
+fn should_build_resource_path() {
+    use twilio_data::Resource;
+
+    const SUBACCOUNT_SID: &str = "ACYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYY";
+    const CONFERENCE_SID: &str = "CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+    const CALL_SID: &str = "CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+    let url = ResourcePath::new(ACCOUNT_SID).resource(Resource::Usage).resource(Resource::Records).json();
+    assert_eq!(url, "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Usage/Records.json");
+
+    let url = ResourcePath::new(ACCOUNT_SID).resource(Resource::Calls).sid(CALL_SID).resource(Resource::Recordings).json();
+    assert_eq!(url, "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Calls/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Recordings.json");
+
+    const HOST: Host<'static> = Host::new().region("sg1").edge("singapore");
+    const URL: twilio_data::ResourceUrl = ResourcePath::with_host(&HOST, SUBACCOUNT_SID).resource(Resource::Conferences)
+                                                                                        .sid(CONFERENCE_SID)
+                                                                                        .resource(Resource::Participants)
+                                                                                        .sid(CALL_SID)
+                                                                                        .json();
+    assert_eq!(URL, "https://api.singapore.sg1.twilio.com/2010-04-01/Accounts/ACYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYY/Conferences/CFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Participants/CAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX.json");
+
+    let url = ResourcePath::new(ACCOUNT_SID).resource(Resource::AvailablePhoneNumbers).segment("US").resource(Resource::Local).into_url();
+    assert_eq!(url, "https://api.twilio.com/2010-04-01/Accounts/ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/AvailablePhoneNumbers/US/Local");
+}
+
+#[test]
+#[should_panic(expected = "ResourcePath overflow")]
+fn should_panic_on_resource_path_overflow() {
+    let long_segment = "X".repeat(128);
+    ResourcePath::new(ACCOUNT_SID).segment(&long_segment).segment(&long_segment);
+}
+
+#[test]
+fn should_build_product_path() {
+    use twilio_data::{ProductPath, Product};
+
+    let url = ProductPath::new(Product::Lookups).segment("PhoneNumbers").segment("+14159929960").into_url();
+    assert_eq!(url, "https://lookups.twilio.com/v2/PhoneNumbers/+14159929960");
+
+    let url = ProductPath::new(Product::Messaging).segment("Services").into_url();
+    assert_eq!(url, "https://messaging.twilio.com/v1/Services");
+
+    let host = Host::new().region("ie1").edge("dublin");
+    let url = ProductPath::with_host(&host, Product::Conversations).segment("Conversations").into_url();
+    assert_eq!(url, "https://conversations.dublin.ie1.twilio.com/v1/Conversations");
+
+    let host = Host::custom("http://localhost:8080");
+    let url = ProductPath::with_host(&host, Product::Verify).segment("Services").into_url();
+    assert_eq!(url, "http://localhost:8080/v2/Services");
+}
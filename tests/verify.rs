@@ -0,0 +1,245 @@
+use twilio_data::{Host, TwilioDateTime};
+use twilio_data::verify::{Service, ServiceList, ServiceCreate, VerificationCreate, VerificationUpdate, VerificationChannel, VerificationStatus, Verification, VerificationCheckCreate, VerificationCheck, VerificationTarget};
+use twilio_data::verify::{FactorCreate, FactorKind, PushFactor, TotpFactor, TotpAlgorithm, FactorVerify, Factor, FactorType, FactorStatus, ChallengeCreate, Challenge, ChallengeStatus, VerifyWebhook, VerifyEvent};
+
+const SERVICE_SID: &str = "VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+const VERIFICATION_SID: &str = "VEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+#[test]
+fn should_build_verify_requests() {
+    let host = Host::new();
+
+    let mut service = ServiceCreate::new("My App");
+    service.code_length = Some(6);
+    service.lookup_enabled = Some(true);
+    assert_eq!(Service::list_url(&host), "https://verify.twilio.com/v2/Services");
+    assert_eq!(service.request().as_form(), "FriendlyName=My+App&CodeLength=6&LookupEnabled=true");
+
+    let mut verification = VerificationCreate::new(SERVICE_SID, "+15017122661", VerificationChannel::Sms);
+    verification.locale = Some("en");
+    verification.custom_friendly_name = Some("Shop \"X\"");
+    verification.rate_limits = &[("end_user_ip_address", "127.0.0.1")];
+    assert_eq!(verification.url(&host), "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Verifications");
+    let request = verification.request();
+    assert_eq!(request.get_field("Channel").as_deref(), Some("sms"));
+    assert_eq!(request.get_field("CustomFriendlyName").as_deref(), Some("Shop \"X\""));
+    assert_eq!(request.get_field("RateLimits").as_deref(), Some(r#"{"end_user_ip_address":"127.0.0.1"}"#));
+
+    let cancel = VerificationUpdate::cancel(SERVICE_SID, VERIFICATION_SID);
+    assert_eq!(cancel.url(&host), "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Verifications/VEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    assert_eq!(cancel.request().as_form(), "Status=canceled");
+    assert_eq!(VerificationUpdate::approve(SERVICE_SID, VERIFICATION_SID).request().as_form(), "Status=approved");
+
+    let check = VerificationCheckCreate::new(SERVICE_SID, VerificationTarget::To("+15017122661"), "123456");
+    assert_eq!(check.url(&host), "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/VerificationCheck");
+    assert_eq!(check.request().as_form(), "To=%2B15017122661&Code=123456");
+    let check = VerificationCheckCreate::new(SERVICE_SID, VerificationTarget::Sid(VERIFICATION_SID), "123456");
+    assert_eq!(check.request().as_form(), "VerificationSid=VEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&Code=123456");
+}
+
+#[test]
+fn should_deserialize_verify_resources() {
+    const SERVICES: &str = r#"{
+        "services": [{
+            "sid": "VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "friendly_name": "My App",
+            "code_length": 6,
+            "lookup_enabled": true,
+            "psd2_enabled": false,
+            "skip_sms_to_landlines": false,
+            "dtmf_input_required": true,
+            "tts_name": "name",
+            "do_not_share_warning_enabled": false,
+            "custom_code_enabled": true,
+            "date_created": "2015-07-30T20:00:00Z",
+            "date_updated": "2015-07-30T20:00:00Z",
+            "url": "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+        }],
+        "meta": {
+            "page": 0,
+            "page_size": 50,
+            "first_page_url": "https://verify.twilio.com/v2/Services?PageSize=50&Page=0",
+            "previous_page_url": null,
+            "next_page_url": null,
+            "key": "services",
+            "url": "https://verify.twilio.com/v2/Services?PageSize=50&Page=0"
+        }
+    }"#;
+    const VERIFICATION: &str = r#"{
+        "sid": "VEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "service_sid": "VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "to": "+15017122661",
+        "channel": "sms",
+        "status": "pending",
+        "valid": false,
+        "date_created": "2015-07-30T20:00:00Z",
+        "date_updated": "2015-07-30T20:00:00Z",
+        "lookup": {},
+        "amount": null,
+        "payee": null,
+        "send_code_attempts": [{
+            "attempt_sid": "VLXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "channel": "sms",
+            "time": "2015-07-30T20:00:00Z"
+        }],
+        "sna": null,
+        "url": "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Verifications/VEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+    }"#;
+    const CHECK: &str = r#"{
+        "sid": "VEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "service_sid": "VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "to": "+15017122661",
+        "channel": "sms",
+        "status": "max_attempts_reached",
+        "valid": false,
+        "amount": null,
+        "payee": null,
+        "sna_attempts_error_codes": [],
+        "date_created": "2015-07-30T20:00:00Z",
+        "date_updated": "2015-07-30T20:00:00Z"
+    }"#;
+
+    let list: ServiceList = serde_json::from_str(SERVICES).unwrap();
+    assert_eq!(list.services[0].code_length, Some(6));
+    assert!(list.services[0].dtmf_input_required);
+    assert_eq!(list.meta.key.as_deref(), Some("services"));
+
+    let verification: Verification = serde_json::from_str(VERIFICATION).unwrap();
+    assert_eq!(verification.status, VerificationStatus::Pending);
+    assert_eq!(verification.channel, Some(VerificationChannel::Sms));
+    assert_eq!(verification.send_code_attempts.len(), 1);
+
+    let check: VerificationCheck = serde_json::from_str(CHECK).unwrap();
+    assert_eq!(check.status, VerificationStatus::MaxAttemptsReached);
+    assert!(!check.is_approved());
+}
+
+#[test]
+fn should_build_factor_and_challenge_requests() {
+    let host = Host::new();
+
+    let totp = FactorCreate {
+        service_sid: SERVICE_SID,
+        identity: "ff483d1ff591898a9942916050d2ca3f",
+        friendly_name: "Authenticator",
+        kind: FactorKind::Totp(TotpFactor {
+            time_step: Some(30),
+            code_length: Some(8),
+            alg: Some(TotpAlgorithm::Sha256),
+            ..Default::default()
+        }),
+    };
+    assert_eq!(totp.url(&host), "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Entities/ff483d1ff591898a9942916050d2ca3f/Factors");
+    assert_eq!(totp.request().as_form(), "FriendlyName=Authenticator&FactorType=totp&Config.TimeStep=30&Config.CodeLength=8&Config.Alg=sha256");
+
+    let push = FactorCreate {
+        kind: FactorKind::Push(PushFactor {
+            public_key: "MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE8GdwtibWe0kpgsFl6xPQBwhtwUEyeJkeozFmi2jiJDzxFSMwVy3kVR1h/dPlYOfgZXNgvdrkvN+/s9i8Wh0OnA==",
+            sdk_version: "1.0.0",
+            app_id: "com.example.app",
+            notification_platform: "fcm",
+            notification_token: Some("token"),
+        }),
+        ..totp
+    };
+    let request = push.request();
+    assert_eq!(request.get_field("FactorType").as_deref(), Some("push"));
+    assert_eq!(request.get_field("Binding.PublicKey").as_deref(), Some("MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE8GdwtibWe0kpgsFl6xPQBwhtwUEyeJkeozFmi2jiJDzxFSMwVy3kVR1h/dPlYOfgZXNgvdrkvN+/s9i8Wh0OnA=="));
+    assert_eq!(request.get_field("Binding.Alg").as_deref(), Some("ES256"));
+    assert_eq!(request.get_field("Config.NotificationToken").as_deref(), Some("token"));
+
+    let verify = FactorVerify::new(SERVICE_SID, "ff483d1ff591898a9942916050d2ca3f", "YFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX", "123456");
+    assert_eq!(verify.url(&host), "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Entities/ff483d1ff591898a9942916050d2ca3f/Factors/YFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    assert_eq!(verify.request().as_form(), "AuthPayload=123456");
+
+    let mut challenge = ChallengeCreate::new(SERVICE_SID, "ff483d1ff591898a9942916050d2ca3f", "YFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    challenge.expiration_date = Some(TwilioDateTime::from_unix(1_600_000_000));
+    challenge.message = Some("Login request");
+    challenge.fields = &[("Action", "Sign in"), ("Location", "California")];
+    challenge.hidden_details = &[("ip", "172.168.1.234")];
+    assert_eq!(challenge.url(&host), "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Entities/ff483d1ff591898a9942916050d2ca3f/Challenges");
+    let request = challenge.request();
+    assert_eq!(request.get_field("ExpirationDate").as_deref(), Some("2020-09-13T12:26:40Z"));
+    assert_eq!(request.get_field("Details.Message").as_deref(), Some("Login request"));
+    assert_eq!(request.get_field("HiddenDetails").as_deref(), Some(r#"{"ip":"172.168.1.234"}"#));
+    let fields: Vec<_> = form_urlencoded::parse(request.as_form().as_bytes()).filter(|(key, _)| key == "Details.Fields").map(|(_, value)| value.into_owned()).collect();
+    assert_eq!(fields, [r#"{"label":"Action","value":"Sign in"}"#, r#"{"label":"Location","value":"California"}"#]);
+}
+
+#[test]
+fn should_deserialize_factors_and_challenges() {
+    const FACTOR: &str = r#"{
+        "sid": "YFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "service_sid": "VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "entity_sid": "YEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "identity": "ff483d1ff591898a9942916050d2ca3f",
+        "binding": {
+            "secret": "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ",
+            "uri": "otpauth://totp/test-issuer:John%E2%80%99s%20Phone?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=test-issuer&algorithm=SHA1&digits=6&period=30"
+        },
+        "date_created": "2015-07-30T20:00:00Z",
+        "date_updated": "2015-07-30T20:00:00Z",
+        "friendly_name": "John's Phone",
+        "status": "unverified",
+        "factor_type": "totp",
+        "config": {
+            "alg": "sha1",
+            "skew": 1,
+            "code_length": 6,
+            "time_step": 30
+        },
+        "metadata": null,
+        "url": "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Entities/ff483d1ff591898a9942916050d2ca3f/Factors/YFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+    }"#;
+    const CHALLENGE: &str = r#"{
+        "sid": "YCXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "account_sid": "ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "service_sid": "VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "entity_sid": "YEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "identity": "ff483d1ff591898a9942916050d2ca3f",
+        "factor_sid": "YFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+        "date_created": "2015-07-30T20:00:00Z",
+        "date_updated": "2015-07-30T20:00:00Z",
+        "date_responded": null,
+        "expiration_date": "2015-07-30T20:05:00Z",
+        "status": "pending",
+        "responded_reason": "none",
+        "details": {
+            "message": "Hi! Mr. John Doe, would you like to sign up?",
+            "date": "2020-07-01T12:13:14Z",
+            "fields": [{"label": "Action", "value": "Sign up in portal"}]
+        },
+        "hidden_details": {"ip": "172.168.1.234"},
+        "metadata": null,
+        "factor_type": "push",
+        "url": "https://verify.twilio.com/v2/Services/VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX/Entities/ff483d1ff591898a9942916050d2ca3f/Challenges/YCXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+    }"#;
+
+    let factor: Factor = serde_json::from_str(FACTOR).unwrap();
+    assert_eq!(factor.status, FactorStatus::Unverified);
+    assert_eq!(factor.factor_type, FactorType::Totp);
+    assert_eq!(factor.binding.unwrap().secret.as_deref(), Some("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ"));
+    assert_eq!(factor.config.unwrap().time_step, Some(30));
+
+    let challenge: Challenge = serde_json::from_str(CHALLENGE).unwrap();
+    assert_eq!(challenge.status, ChallengeStatus::Pending);
+    let details = challenge.details.unwrap();
+    assert_eq!(details.fields[0].label, "Action");
+    assert_eq!(challenge.hidden_details.unwrap()["ip"], "172.168.1.234");
+}
+
+#[test]
+fn should_deserialize_verify_webhook() {
+    const FORM: &str = "uuid=75d9b5e8-8f4f-4a1a-9c2a-6a3f1e8b3f8e&type=challenge.approved&account_sid=ACXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&service_sid=VAXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&entity_identity=ff483d1ff591898a9942916050d2ca3f&factor_sid=YFXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&factor_type=push&factor_friendly_name=John%27s+Phone&challenge_sid=YCXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX&challenge_details=%7B%22message%22%3A%22Hi%22%7D&challenge_status=approved&challenge_responded_reason=none";
+
+    let webhook: VerifyWebhook = serde_urlencoded::from_str(FORM).unwrap();
+    assert!(webhook.is_challenge_approved());
+    assert_eq!(webhook.event, VerifyEvent::ChallengeApproved);
+    assert_eq!(webhook.factor_type, Some(FactorType::Push));
+    assert_eq!(webhook.challenge_status, Some(ChallengeStatus::Approved));
+    assert_eq!(webhook.challenge_details.as_deref(), Some(r#"{"message":"Hi"}"#));
+}